@@ -26,7 +26,8 @@
 //! This implementation provides a complete toolkit for managing the technical aspects
 //! of asset tokenization while ensuring regulatory compliance and proper identity management.
 
-use cosmrs::crypto::secp256k1::SigningKey;
+use std::sync::Arc;
+
 use erc3643sdk::{
     compliance::request::ComplianceModuleRequest,
     identity::{
@@ -34,7 +35,7 @@ use erc3643sdk::{
         Claim,
     },
     token::request::{TokenInfoRequest, TransferMessageRequest},
-    RwaClient,
+    Address, GasSetting, LocalSigner, RwaClient, RwaClientBuilder,
 };
 
 /// Claim topics for different verification types.
@@ -54,7 +55,7 @@ struct AssetTokenization {
     /// RWA client instance for blockchain interactions
     client: RwaClient,
     /// Address of the asset issuer
-    issuer_address: String,
+    issuer_address: Address,
 }
 
 impl AssetTokenization {
@@ -74,8 +75,8 @@ impl AssetTokenization {
         let identity_request = AddIdentityRequest {
             from: self.issuer_address.clone(),
             country: "US".to_string(),
-            signer: SigningKey::from_slice(&[/* your private key */])?,
-            gas_limit: 10,
+            signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+            gas_limit: GasSetting::default(),
         };
 
         let identity_result = self.client.add_identity(identity_request).await?;
@@ -85,13 +86,13 @@ impl AssetTokenization {
             from: self.issuer_address.clone(),
             claim: Claim {
                 topic: ASSET_OWNERSHIP_CLAIM_TOPIC.into(),
-                issuer: self.issuer_address.clone(),
+                issuer: self.issuer_address.to_string(),
                 data: [].into(),
                 uri: "ipfs://asset-documents-hash".to_string(),
             },
             identity_owner: self.issuer_address.clone(),
-            signer: SigningKey::from_slice(&[/* your private key */])?,
-            gas_limit: 10,
+            signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+            gas_limit: GasSetting::default(),
         };
 
         self.client.add_claim(ownership_claim).await?;
@@ -114,9 +115,9 @@ impl AssetTokenization {
         // Set up country restriction module for geographic compliance
         let cr_module_request = ComplianceModuleRequest {
             from: self.issuer_address.clone(),
-            module_addr: "cosmos1cr...".to_string(),
-            signer: SigningKey::from_slice(&[/* your private key */])?,
-            gas_limit: 10,
+            module_addr: "cosmos1cr...".parse()?,
+            signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+            gas_limit: GasSetting::default(),
         };
 
         let cr_result = self
@@ -144,10 +145,10 @@ impl AssetTokenization {
     ) -> Result<String, Box<dyn std::error::Error>> {
         // Create investor's digital identity
         let investor_identity = AddIdentityRequest {
-            from: investor_address.to_string(),
+            from: investor_address.parse()?,
             country: "US".to_string(),
-            signer: SigningKey::from_slice(&[/* your private key */])?,
-            gas_limit: 10,
+            signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+            gas_limit: GasSetting::default(),
         };
 
         let identity_result = self.client.add_identity(investor_identity).await?;
@@ -157,13 +158,13 @@ impl AssetTokenization {
             from: self.issuer_address.clone(),
             claim: Claim {
                 topic: KYC_CLAIM_TOPIC.into(),
-                issuer: self.issuer_address.clone(),
+                issuer: self.issuer_address.to_string(),
                 data: kyc_data.into(),
                 uri: "ipfs://kyc-documents-hash".to_string(),
             },
-            identity_owner: investor_address.to_string(),
-            signer: SigningKey::from_slice(&[/* your private key */])?,
-            gas_limit: 10,
+            identity_owner: investor_address.parse()?,
+            signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+            gas_limit: GasSetting::default(),
         };
 
         self.client.add_claim(kyc_claim).await?;
@@ -188,8 +189,8 @@ impl AssetTokenization {
     ) -> Result<String, Box<dyn std::error::Error>> {
         // Verify investor's compliance status
         let compliance_check = CheckUserForTokenComplianceRequest {
-            token_address: "cosmos1token...".to_string(),
-            from: investor_address.to_string(),
+            token_address: "cosmos1token...".parse()?,
+            from: investor_address.parse()?,
         };
 
         let is_compliant = self.client.check_token_compliance(compliance_check).await?;
@@ -201,10 +202,10 @@ impl AssetTokenization {
         // Execute compliant token transfer
         let transfer_request = TransferMessageRequest {
             from: self.issuer_address.clone(),
-            to: investor_address.to_string(),
+            to: investor_address.parse()?,
             amount,
-            signer: SigningKey::from_slice(&[/* your private key */])?,
-            gas_limit: 10,
+            signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+            gas_limit: GasSetting::default(),
         };
 
         let transfer_result = self.client.transfer(transfer_request).await?;
@@ -226,14 +227,14 @@ impl AssetTokenization {
     ) -> Result<(u128, bool), Box<dyn std::error::Error>> {
         // Query current token balance
         let balance_request = TokenInfoRequest {
-            address: investor_address.to_string(),
+            address: investor_address.parse()?,
         };
         let balance = self.client.balance(balance_request).await?;
 
         // Verify current compliance status
         let compliance_check = CheckUserForTokenComplianceRequest {
-            token_address: "cosmos1token...".to_string(),
-            from: investor_address.to_string(),
+            token_address: "cosmos1token...".parse()?,
+            from: investor_address.parse()?,
         };
         let is_compliant = self.client.check_token_compliance(compliance_check).await?;
 
@@ -243,5 +244,31 @@ impl AssetTokenization {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let tokenization = AssetTokenization {
+        // Network settings are read from RWA_RPC_URL, RWA_CHAIN_ID, RWA_TOKEN_ADDRESS, ...
+        client: RwaClientBuilder::from_env()?.build()?,
+        issuer_address: "cosmos1issuer...".parse()?,
+    };
+
+    let identity_tx = tokenization.setup_issuer_identity().await?;
+    println!("Issuer identity transaction hash: {}", identity_tx);
+
+    let compliance_tx = tokenization.setup_compliance().await?;
+    println!("Compliance setup transaction hash: {}", compliance_tx);
+
+    let investor_address = "cosmos1investor...";
+    let investor_tx = tokenization
+        .register_investor(investor_address, b"kyc data".to_vec())
+        .await?;
+    println!("Investor registration transaction hash: {}", investor_tx);
+
+    let distribution_tx = tokenization
+        .distribute_tokens(investor_address, 1_000)
+        .await?;
+    println!("Token distribution transaction hash: {}", distribution_tx);
+
+    let (balance, is_compliant) = tokenization.get_investment_status(investor_address).await?;
+    println!("Investor balance: {}, compliant: {}", balance, is_compliant);
+
     Ok(())
 }
@@ -1,24 +1,26 @@
-use cosmrs::crypto::secp256k1::SigningKey;
-use erc3643sdk::{compliance::request::ComplianceModuleRequest, RwaClient};
+use std::sync::Arc;
+
+use erc3643sdk::{
+    compliance::request::ComplianceModuleRequest, GasSetting, LocalSigner, RwaClient,
+};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = RwaClient::new(
-        "rpc_url",
-        "chain_id",
-        "token_address",
-        "identity_address",
-        "compliance_address",
-        "sei",
-        10,
-    )?;
+    let client = RwaClient::builder()
+        .rpc_url("rpc_url")
+        .chain_id("chain_id")
+        .token_address("token_address")
+        .identity_address("identity_address")
+        .compliance_address("compliance_address")
+        .gas_prices("0.1usei")
+        .build()?;
 
     // Add a compliance module
     let add_module_request = ComplianceModuleRequest {
-        from: "cosmos1sender...".to_string(),
-        module_addr: "kyc_module_addr...".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        from: "cosmos1sender...".parse()?,
+        module_addr: "kyc_module_addr...".parse()?,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let add_result = client
         .add_compliance_module("KYCModule", add_module_request)
@@ -30,10 +32,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Update a compliance module (set to active)
     let update_module_request = ComplianceModuleRequest {
-        from: "cosmos1sender...".to_string(),
-        module_addr: "cosmos1module...".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        from: "cosmos1sender...".parse()?,
+        module_addr: "cosmos1module...".parse()?,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let update_result = client
         .update_compliance_module(update_module_request, false)
@@ -45,10 +47,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Remove a compliance module
     let remove_module_request = ComplianceModuleRequest {
-        from: "cosmos1sender...".to_string(),
-        module_addr: "cosmos1module...".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        from: "cosmos1sender...".parse()?,
+        module_addr: "cosmos1module...".parse()?,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let remove_result = client
         .remove_compliance_module(remove_module_request)
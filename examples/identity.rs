@@ -1,42 +1,42 @@
-use cosmrs::crypto::secp256k1::SigningKey;
+use std::sync::Arc;
+
 use cosmwasm_std::{Binary, Uint128};
 use erc3643sdk::identity::request::{
     AddClaimRequest, AddIdentityRequest, CheckUserForTokenComplianceRequest,
     GetValidatedClaimsRequest, RemoveClaimRequest, RemoveIdentityRequest, UpdateIdentityRequest,
 };
 use erc3643sdk::identity::Claim;
-use erc3643sdk::RwaClient;
+use erc3643sdk::{GasSetting, LocalSigner, RwaClient};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize the client
-    let client = RwaClient::new(
-        "http://rpc.example.com:26657",
-        "my-chain-id",
-        "cosmos1token...",
-        "cosmos1identity...",
-        "cosmos1compliance...",
-        "sei",
-        10,
-    )?;
+    let client = RwaClient::builder()
+        .rpc_url("http://rpc.example.com:26657")
+        .chain_id("my-chain-id")
+        .token_address("cosmos1token...")
+        .identity_address("cosmos1identity...")
+        .compliance_address("cosmos1compliance...")
+        .gas_prices("0.1usei")
+        .build()?;
 
     // Add a new identity
     let add_identity_request = AddIdentityRequest {
-        from: "cosmos1sender...".to_string(),
+        from: "cosmos1sender...".parse()?,
         country: "US".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let add_result = client.add_identity(add_identity_request).await?;
     println!("Add identity transaction hash: {}", add_result.tx_hash);
 
     // Update an identity
     let update_identity_request = UpdateIdentityRequest {
-        from: "cosmos1sender...".to_string(),
+        from: "cosmos1sender...".parse()?,
         new_country: "CA".to_string(),
-        identity_owner: "cosmos1owner...".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        identity_owner: "cosmos1owner...".parse()?,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let update_result = client.update_identity(update_identity_request).await?;
     println!(
@@ -46,34 +46,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Add a claim to an identity
     let add_claim_request = AddClaimRequest {
-        from: "cosmos1issuer...".to_string(),
+        from: "cosmos1issuer...".parse()?,
         claim: Claim {
             topic: Uint128::new(1),
             issuer: "cosmos1issuer...".to_string(),
             data: Binary::from(b"claim data"),
             uri: "https://example.com/claim".to_string(),
         },
-        identity_owner: "cosmos1owner...".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        identity_owner: "cosmos1owner...".parse()?,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let add_claim_result = client.add_claim(add_claim_request).await?;
     println!("Add claim transaction hash: {}", add_claim_result.tx_hash);
 
     // Get validated claims for an identity
     let get_claims_request = GetValidatedClaimsRequest {
-        identity_owner: "cosmos1owner...".to_string(),
+        identity_owner: "cosmos1owner...".parse()?,
     };
     let claims = client.get_validated_claims(get_claims_request).await?;
     println!("Validated claims: {:?}", claims);
 
     // Remove a claim from an identity
     let remove_claim_request = RemoveClaimRequest {
-        from: "cosmos1issuer...".to_string(),
+        from: "cosmos1issuer...".parse()?,
         claim_topic: Uint128::new(1),
-        identity_owner: "cosmos1owner...".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        identity_owner: "cosmos1owner...".parse()?,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let remove_claim_result = client.remove_claim(remove_claim_request).await?;
     println!(
@@ -83,10 +83,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Remove an identity
     let remove_identity_request = RemoveIdentityRequest {
-        from: "cosmos1sender...".to_string(),
-        identity_owner: "cosmos1owner...".to_string(),
-        signer: SigningKey::from_slice(&[/* your private key */])?,
-        gas_limit: 5000,
+        from: "cosmos1sender...".parse()?,
+        identity_owner: "cosmos1owner...".parse()?,
+        signer: Arc::new(LocalSigner::from_slice(&[/* your private key */])?),
+        gas_limit: GasSetting::default(),
     };
     let remove_result = client.remove_identity(remove_identity_request).await?;
     println!(
@@ -96,8 +96,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Check token compliance for a user
     let compliance_request = CheckUserForTokenComplianceRequest {
-        token_address: "cosmos1token...".to_string(),
-        from: "cosmos1user...".to_string(),
+        token_address: "cosmos1token...".parse()?,
+        from: "cosmos1user...".parse()?,
     };
     let is_compliant = client.check_token_compliance(compliance_request).await?;
     println!("Is user compliant: {}", is_compliant);
@@ -1,37 +1,37 @@
-use cosmrs::crypto::secp256k1::SigningKey;
+use std::sync::Arc;
+
 use erc3643sdk::{
     token::request::{TokenInfoRequest, TransferMessageRequest},
-    RwaClient,
+    GasSetting, LocalSigner, RwaClient,
 };
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = RwaClient::new(
-        "rpc_url",
-        "chain_id",
-        "token_address",
-        "identity_address",
-        "compliance_address",
-        "sei",
-        10,
-    )?;
+    let client = RwaClient::builder()
+        .rpc_url("rpc_url")
+        .chain_id("chain_id")
+        .token_address("token_address")
+        .identity_address("identity_address")
+        .compliance_address("compliance_address")
+        .gas_prices("0.1usei")
+        .build()?;
 
-    let signer = SigningKey::from_slice(&[/* your private key */])?;
+    let signer = Arc::new(LocalSigner::from_slice(&[/* your private key */])?);
 
     // Perform a token transfer
     let transfer_request = TransferMessageRequest {
-        from: "cosmos1sender...".to_string(),
-        to: "cosmos1recipient...".to_string(),
+        from: "cosmos1sender...".parse()?,
+        to: "cosmos1recipient...".parse()?,
         amount: 100,
-        signer: signer,
-        gas_limit: 5000,
+        signer,
+        gas_limit: GasSetting::default(),
     };
     let transfer_result = client.transfer(transfer_request).await?;
     println!("Transfer hash: {}", transfer_result.tx_hash);
 
     // Check balance
     let balance_request = TokenInfoRequest {
-        address: "cosmos1sender...".to_string(),
+        address: "cosmos1sender...".parse()?,
     };
     let balance = client.balance(balance_request).await?;
     println!("Balance: {}", balance.balance);
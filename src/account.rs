@@ -0,0 +1,140 @@
+//! Account types for the RWA SDK.
+//!
+//! The auth module returns an account packed in an `Any` whose type depends
+//! on the account: a `BaseAccount`, a vesting or module account wrapping one,
+//! or a chain-specific type such as the `EthAccount` of EVM-enabled chains.
+//! The client only needs the account number and sequence of the base account,
+//! so [`AccountTypes`] maps each type URL to the extraction of its base
+//! account. Chains with other account types register them with
+//! [`RwaClientBuilder::account_type`](crate::RwaClientBuilder::account_type).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, ModuleAccount};
+use cosmrs::proto::cosmos::vesting::v1beta1::{
+    ContinuousVestingAccount, DelayedVestingAccount, PeriodicVestingAccount, PermanentLockedAccount,
+};
+use cosmrs::proto::prost::{Message, Name};
+use cosmrs::{AccountId, Any};
+
+use crate::RwaError;
+
+/// `ErrKeyNotFound` code in the `sdk` codespace, returned for an unknown account.
+pub(crate) const KEY_NOT_FOUND_CODE: u32 = 22;
+
+/// Type URL of the `EthAccount` of Ethermint-based chains.
+pub const ETH_ACCOUNT_TYPE_URL: &str = "/ethermint.types.v1.EthAccount";
+
+/// The `EthAccount` of Ethermint-based chains: a base account with the hash
+/// of its EVM code.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EthAccount {
+    #[prost(message, optional, tag = "1")]
+    pub base_account: Option<BaseAccount>,
+    #[prost(string, tag = "2")]
+    pub code_hash: String,
+}
+
+type BaseAccountDecoder =
+    dyn Fn(&[u8]) -> Result<Option<BaseAccount>, prost::DecodeError> + Send + Sync;
+
+/// The account types the client can decode, by type URL.
+///
+/// The base, module and vesting accounts of the Cosmos SDK and the
+/// `EthAccount` are known by default.
+#[derive(Clone)]
+pub struct AccountTypes {
+    decoders: HashMap<String, Arc<BaseAccountDecoder>>,
+}
+
+impl Default for AccountTypes {
+    fn default() -> Self {
+        let mut account_types = Self {
+            decoders: HashMap::new(),
+        };
+        account_types.register(&BaseAccount::type_url(), |account: BaseAccount| {
+            Some(account)
+        });
+        account_types.register(&ModuleAccount::type_url(), |account: ModuleAccount| {
+            account.base_account
+        });
+        account_types.register(
+            &ContinuousVestingAccount::type_url(),
+            |account: ContinuousVestingAccount| account.base_vesting_account?.base_account,
+        );
+        account_types.register(
+            &DelayedVestingAccount::type_url(),
+            |account: DelayedVestingAccount| account.base_vesting_account?.base_account,
+        );
+        account_types.register(
+            &PeriodicVestingAccount::type_url(),
+            |account: PeriodicVestingAccount| account.base_vesting_account?.base_account,
+        );
+        account_types.register(
+            &PermanentLockedAccount::type_url(),
+            |account: PermanentLockedAccount| account.base_vesting_account?.base_account,
+        );
+        account_types.register(ETH_ACCOUNT_TYPE_URL, |account: EthAccount| {
+            account.base_account
+        });
+        account_types
+    }
+}
+
+impl fmt::Debug for AccountTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut type_urls: Vec<&String> = self.decoders.keys().collect();
+        type_urls.sort();
+        f.debug_struct("AccountTypes")
+            .field("type_urls", &type_urls)
+            .finish()
+    }
+}
+
+impl AccountTypes {
+    /// Registers an account type, replacing any decoding of the same type URL.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_url` - The type URL of the account, e.g. `/ethermint.types.v1.EthAccount`
+    /// * `base_account` - Returns the base account of a decoded account, if it has one
+    pub fn register<M, F>(&mut self, type_url: &str, base_account: F)
+    where
+        M: Message + Default + 'static,
+        F: Fn(M) -> Option<BaseAccount> + Send + Sync + 'static,
+    {
+        self.decoders.insert(
+            type_url.to_string(),
+            Arc::new(move |bytes| Ok(base_account(M::decode(bytes)?))),
+        );
+    }
+
+    /// Returns the base account of an account returned by the auth module.
+    pub(crate) fn base_account(
+        &self,
+        account_id: &AccountId,
+        account: &Any,
+    ) -> Result<BaseAccount, RwaError> {
+        let decoder = self.decoders.get(&account.type_url).ok_or_else(|| {
+            RwaError::account_lookup(
+                account_id,
+                format!(
+                    "unsupported account type `{}`, register it with \
+                     `RwaClientBuilder::account_type`",
+                    account.type_url
+                ),
+            )
+        })?;
+
+        decoder(&account.value)
+            .map_err(|e| RwaError::account_lookup(account_id, e))?
+            .ok_or_else(|| {
+                RwaError::account_lookup(
+                    account_id,
+                    format!("account of type `{}` has no base account", account.type_url),
+                )
+            })
+    }
+}
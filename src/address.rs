@@ -0,0 +1,229 @@
+//! Account addresses for the RWA SDK.
+//!
+//! KiiChain exposes every account both as a bech32 address (`kii1...`) and as
+//! a `0x` EVM address over the same 20 bytes. [`Address`] parses either form,
+//! validating the bech32 checksum or the EIP-55 mixed-case checksum, and
+//! converts between them. Request structures and batch operations take an
+//! `Address`, and the client renders it with the bech32 prefix of its chain.
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use cosmrs::AccountId;
+use sha3::{Digest, Keccak256};
+
+use crate::RwaError;
+
+/// Length in bytes of an EVM address.
+pub const EVM_ADDRESS_LENGTH: usize = 20;
+
+/// An account or contract address, parsed from its bech32 or `0x` hex form.
+///
+/// Two addresses are equal when their bytes are, whatever form they were
+/// given in.
+#[derive(Debug, Clone)]
+pub struct Address {
+    bytes: Vec<u8>,
+    /// The bech32 prefix the address was given with, `None` for a hex address
+    hrp: Option<String>,
+}
+
+impl Address {
+    /// Creates an address from its raw bytes, without a bech32 prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The address bytes, 20 for an account and 32 for a contract
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        Self {
+            bytes: bytes.into(),
+            hrp: None,
+        }
+    }
+
+    /// Parses a bech32 address, validating its checksum.
+    pub fn from_bech32(address: &str) -> Result<Self, RwaError> {
+        let account_id =
+            AccountId::from_str(address).map_err(|e| RwaError::invalid_address(address, e))?;
+        Ok(account_id.into())
+    }
+
+    /// Parses a `0x` EVM address.
+    ///
+    /// An address in mixed case must match its EIP-55 checksum, while an
+    /// all lowercase or all uppercase address carries no checksum.
+    pub fn from_hex(address: &str) -> Result<Self, RwaError> {
+        let digits = address
+            .strip_prefix("0x")
+            .or_else(|| address.strip_prefix("0X"))
+            .ok_or_else(|| RwaError::invalid_address(address, "missing `0x` prefix"))?;
+        if digits.len() != EVM_ADDRESS_LENGTH * 2 {
+            return Err(RwaError::invalid_address(
+                address,
+                format!("expected {} hex digits", EVM_ADDRESS_LENGTH * 2),
+            ));
+        }
+        let bytes = hex::decode(digits).map_err(|e| RwaError::invalid_address(address, e))?;
+
+        let mixed_case = digits.chars().any(|c| c.is_ascii_lowercase())
+            && digits.chars().any(|c| c.is_ascii_uppercase());
+        if mixed_case && checksum_digits(&digits.to_ascii_lowercase()) != digits {
+            return Err(RwaError::invalid_address(
+                address,
+                "invalid EIP-55 checksum",
+            ));
+        }
+
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Returns the raw bytes of the address.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bech32 prefix the address was given with, if any.
+    pub fn hrp(&self) -> Option<&str> {
+        self.hrp.as_deref()
+    }
+
+    /// Returns the address as an account ID with the given bech32 prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `hrp` - The bech32 prefix, e.g. `kii`
+    pub fn to_account_id(&self, hrp: &str) -> Result<AccountId, RwaError> {
+        AccountId::new(hrp, &self.bytes)
+            .map_err(|e| RwaError::invalid_address(&self.to_string(), e))
+    }
+
+    /// Returns the bech32 form of the address with the given prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `hrp` - The bech32 prefix, e.g. `kii`
+    pub fn to_bech32(&self, hrp: &str) -> Result<String, RwaError> {
+        Ok(self.to_account_id(hrp)?.to_string())
+    }
+
+    /// Returns the EIP-55 checksummed `0x` form of the address.
+    ///
+    /// Only 20 byte addresses have an EVM form; contract addresses of 32
+    /// bytes return an error.
+    pub fn to_hex(&self) -> Result<String, RwaError> {
+        if self.bytes.len() != EVM_ADDRESS_LENGTH {
+            return Err(RwaError::invalid_address(
+                &format!("0x{}", hex::encode(&self.bytes)),
+                format!("{} byte address has no EVM form", self.bytes.len()),
+            ));
+        }
+        Ok(format!("0x{}", checksum_digits(&hex::encode(&self.bytes))))
+    }
+
+    /// Returns the account ID of the address on a chain.
+    ///
+    /// A bech32 address given with another prefix is rejected rather than
+    /// silently moved to the chain's prefix.
+    pub(crate) fn on_chain(&self, hrp: &str) -> Result<AccountId, RwaError> {
+        match &self.hrp {
+            Some(own) if own != hrp => Err(RwaError::invalid_address(
+                &self.to_string(),
+                format!("expected the `{}` prefix", hrp),
+            )),
+            _ => self.to_account_id(hrp),
+        }
+    }
+}
+
+impl PartialEq for Address {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for Address {}
+
+impl Hash for Address {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+    }
+}
+
+/// Applies the EIP-55 checksum to the lowercase hex digits of an address.
+fn checksum_digits(lowercase: &str) -> String {
+    let hash = Keccak256::digest(lowercase.as_bytes());
+    lowercase
+        .chars()
+        .enumerate()
+        .map(|(index, c)| {
+            let nibble = (hash[index / 2] >> (4 * (1 - index % 2))) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+impl FromStr for Address {
+    type Err = RwaError;
+
+    /// Parses a `0x` EVM address or a bech32 address.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if address.starts_with("0x") || address.starts_with("0X") {
+            Self::from_hex(address)
+        } else {
+            Self::from_bech32(address)
+        }
+    }
+}
+
+impl TryFrom<&str> for Address {
+    type Error = RwaError;
+
+    fn try_from(address: &str) -> Result<Self, Self::Error> {
+        address.parse()
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = RwaError;
+
+    fn try_from(address: String) -> Result<Self, Self::Error> {
+        address.parse()
+    }
+}
+
+impl From<AccountId> for Address {
+    fn from(account_id: AccountId) -> Self {
+        Self {
+            bytes: account_id.to_bytes(),
+            hrp: Some(account_id.prefix().to_string()),
+        }
+    }
+}
+
+impl From<&AccountId> for Address {
+    fn from(account_id: &AccountId) -> Self {
+        account_id.clone().into()
+    }
+}
+
+impl fmt::Display for Address {
+    /// Formats the address in the form it was given: bech32 with its
+    /// prefix, or EIP-55 checksummed hex.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.hrp {
+            Some(hrp) => {
+                let account_id = AccountId::new(hrp, &self.bytes).map_err(|_| fmt::Error)?;
+                write!(f, "{}", account_id)
+            }
+            None => match self.to_hex() {
+                Ok(address) => f.write_str(&address),
+                Err(_) => write!(f, "0x{}", hex::encode(&self.bytes)),
+            },
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Legacy Amino JSON sign documents.
+//!
+//! Signers using `SIGN_MODE_LEGACY_AMINO_JSON`, such as hardware wallets,
+//! sign the canonical JSON `StdSignDoc` of a transaction instead of its
+//! protobuf `SignDoc`: object keys sorted, no whitespace, integers as strings
+//! and `&`, `<`, `>` escaped as in Go's `encoding/json`.
+
+use cosmrs::proto::cosmos::authz::v1beta1::{GenericAuthorization, MsgExec, MsgGrant, MsgRevoke};
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    AllowedMsgAllowance, BasicAllowance, MsgGrantAllowance, MsgRevokeAllowance, PeriodicAllowance,
+};
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    AcceptedMessageKeysFilter, AllowAllMessagesFilter, CombinedLimit,
+    ContractExecutionAuthorization, MaxCallsLimit, MaxFundsLimit, MsgExecuteContract,
+};
+use cosmrs::proto::prost::Name;
+use cosmrs::proto::tendermint::google::protobuf::Duration;
+use cosmrs::proto::Timestamp;
+use cosmrs::tendermint::Time;
+use cosmrs::tx::{AuthInfo, Body};
+use cosmrs::{Any, Coin};
+use serde_json::{json, Value};
+
+use crate::RwaError;
+
+/// Seconds from the Unix epoch to Go's zero time, `0001-01-01T00:00:00Z`.
+const ZERO_TIME_SECONDS: i64 = -62_135_596_800;
+
+/// Amino type of `MsgExecuteContract`.
+const EXECUTE_CONTRACT_AMINO_TYPE: &str = "wasm/MsgExecuteContract";
+
+/// Amino type of the authz `MsgExec`.
+const EXEC_AMINO_TYPE: &str = "cosmos-sdk/MsgExec";
+
+/// Amino type of the authz `MsgGrant`.
+const GRANT_AMINO_TYPE: &str = "cosmos-sdk/MsgGrant";
+
+/// Amino type of the authz `MsgRevoke`.
+const REVOKE_AMINO_TYPE: &str = "cosmos-sdk/MsgRevoke";
+
+/// Amino type of the feegrant `MsgGrantAllowance`.
+const GRANT_ALLOWANCE_AMINO_TYPE: &str = "cosmos-sdk/MsgGrantAllowance";
+
+/// Amino type of the feegrant `MsgRevokeAllowance`.
+const REVOKE_ALLOWANCE_AMINO_TYPE: &str = "cosmos-sdk/MsgRevokeAllowance";
+
+/// Builds the Amino JSON sign bytes of a transaction.
+///
+/// # Arguments
+///
+/// * `body` - The body of the transaction
+/// * `auth_info` - The auth info of the transaction, holding the fee
+/// * `chain_id` - The chain the transaction is valid on
+/// * `account_number` - The account number of the signer
+/// * `sequence` - The account sequence of the signer
+///
+/// # Returns
+///
+/// A Result containing the sign bytes, or an error if a message has no Amino
+/// JSON encoding
+pub(crate) fn sign_bytes(
+    body: &Body,
+    auth_info: &AuthInfo,
+    chain_id: &str,
+    account_number: u64,
+    sequence: u64,
+) -> Result<Vec<u8>, RwaError> {
+    let msgs = body
+        .messages
+        .iter()
+        .map(amino_msg)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let fee = &auth_info.fee;
+    let mut amino_fee = json!({
+        "amount": coins(&fee.amount),
+        "gas": fee.gas_limit.to_string(),
+    });
+    if let Some(payer) = &fee.payer {
+        amino_fee["payer"] = payer.to_string().into();
+    }
+    if let Some(granter) = &fee.granter {
+        amino_fee["granter"] = granter.to_string().into();
+    }
+
+    let mut sign_doc = json!({
+        "account_number": account_number.to_string(),
+        "chain_id": chain_id,
+        "fee": amino_fee,
+        "memo": body.memo,
+        "msgs": msgs,
+        "sequence": sequence.to_string(),
+    });
+    if body.timeout_height.value() != 0 {
+        sign_doc["timeout_height"] = body.timeout_height.to_string().into();
+    }
+
+    // Keys are sorted by serde_json's map, only the escaping is left
+    let sign_doc = serde_json::to_string(&sign_doc)
+        .map_err(|e| RwaError::Encoding(e.to_string()))?
+        .replace('&', "\\u0026")
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e");
+    Ok(sign_doc.into_bytes())
+}
+
+/// Converts a message into its Amino JSON form, `{"type": ..., "value": ...}`.
+fn amino_msg(msg: &Any) -> Result<Value, RwaError> {
+    if msg.type_url == MsgExecuteContract::type_url() {
+        let msg: MsgExecuteContract = msg.to_msg()?;
+        // The contract message is embedded as JSON, not as base64 bytes
+        let contract_msg: Value = serde_json::from_slice(&msg.msg)
+            .map_err(|e| RwaError::Encoding(format!("invalid contract message: {}", e)))?;
+        let funds = msg
+            .funds
+            .into_iter()
+            .map(|coin| json!({ "amount": coin.amount, "denom": coin.denom }))
+            .collect::<Vec<_>>();
+
+        return Ok(json!({
+            "type": EXECUTE_CONTRACT_AMINO_TYPE,
+            "value": {
+                "contract": msg.contract,
+                "funds": funds,
+                "msg": contract_msg,
+                "sender": msg.sender,
+            },
+        }));
+    }
+
+    if msg.type_url == MsgExec::type_url() {
+        let msg: MsgExec = msg.to_msg()?;
+        let msgs = msg
+            .msgs
+            .iter()
+            .map(amino_msg)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        return Ok(json!({
+            "type": EXEC_AMINO_TYPE,
+            "value": {
+                "grantee": msg.grantee,
+                "msgs": msgs,
+            },
+        }));
+    }
+
+    if msg.type_url == MsgGrant::type_url() {
+        let msg: MsgGrant = msg.to_msg()?;
+        let grant = msg.grant.unwrap_or_default();
+        let mut amino_grant = json!({});
+        if let Some(authorization) = &grant.authorization {
+            amino_grant["authorization"] = amino_any(authorization)?;
+        }
+        if let Some(expiration) = &grant.expiration {
+            amino_grant["expiration"] = timestamp(expiration)?.into();
+        }
+
+        return Ok(json!({
+            "type": GRANT_AMINO_TYPE,
+            "value": {
+                "grant": amino_grant,
+                "grantee": msg.grantee,
+                "granter": msg.granter,
+            },
+        }));
+    }
+
+    if msg.type_url == MsgRevoke::type_url() {
+        let msg: MsgRevoke = msg.to_msg()?;
+
+        return Ok(json!({
+            "type": REVOKE_AMINO_TYPE,
+            "value": {
+                "grantee": msg.grantee,
+                "granter": msg.granter,
+                "msg_type_url": msg.msg_type_url,
+            },
+        }));
+    }
+
+    if msg.type_url == MsgGrantAllowance::type_url() {
+        let msg: MsgGrantAllowance = msg.to_msg()?;
+        let mut value = json!({
+            "grantee": msg.grantee,
+            "granter": msg.granter,
+        });
+        if let Some(allowance) = &msg.allowance {
+            value["allowance"] = amino_any(allowance)?;
+        }
+
+        return Ok(json!({
+            "type": GRANT_ALLOWANCE_AMINO_TYPE,
+            "value": value,
+        }));
+    }
+
+    if msg.type_url == MsgRevokeAllowance::type_url() {
+        let msg: MsgRevokeAllowance = msg.to_msg()?;
+
+        return Ok(json!({
+            "type": REVOKE_ALLOWANCE_AMINO_TYPE,
+            "value": {
+                "grantee": msg.grantee,
+                "granter": msg.granter,
+            },
+        }));
+    }
+
+    Err(RwaError::Encoding(format!(
+        "message type {} has no Amino JSON encoding",
+        msg.type_url
+    )))
+}
+
+/// Converts a value packed in a message, such as an authorization or a fee
+/// allowance, into its Amino JSON form.
+fn amino_any(any: &Any) -> Result<Value, RwaError> {
+    let (amino_type, value) = match any.type_url.as_str() {
+        url if url == GenericAuthorization::type_url() => {
+            let authorization: GenericAuthorization = any.to_msg()?;
+            (
+                "cosmos-sdk/GenericAuthorization",
+                json!({ "msg": authorization.msg }),
+            )
+        }
+        url if url == ContractExecutionAuthorization::type_url() => {
+            let authorization: ContractExecutionAuthorization = any.to_msg()?;
+            let grants = authorization
+                .grants
+                .iter()
+                .map(|grant| {
+                    let mut amino_grant = json!({ "contract": grant.contract });
+                    if let Some(limit) = &grant.limit {
+                        amino_grant["limit"] = amino_any(limit)?;
+                    }
+                    if let Some(filter) = &grant.filter {
+                        amino_grant["filter"] = amino_any(filter)?;
+                    }
+                    Ok(amino_grant)
+                })
+                .collect::<Result<Vec<_>, RwaError>>()?;
+            (
+                "wasm/ContractExecutionAuthorization",
+                json!({ "grants": grants }),
+            )
+        }
+        url if url == MaxCallsLimit::type_url() => {
+            let limit: MaxCallsLimit = any.to_msg()?;
+            (
+                "wasm/MaxCallsLimit",
+                json!({ "remaining": limit.remaining.to_string() }),
+            )
+        }
+        url if url == MaxFundsLimit::type_url() => {
+            let limit: MaxFundsLimit = any.to_msg()?;
+            (
+                "wasm/MaxFundsLimit",
+                json!({ "amounts": proto_coins(&limit.amounts) }),
+            )
+        }
+        url if url == CombinedLimit::type_url() => {
+            let limit: CombinedLimit = any.to_msg()?;
+            (
+                "wasm/CombinedLimit",
+                json!({
+                    "amounts": proto_coins(&limit.amounts),
+                    "calls_remaining": limit.calls_remaining.to_string(),
+                }),
+            )
+        }
+        url if url == AllowAllMessagesFilter::type_url() => {
+            ("wasm/AllowAllMessagesFilter", json!({}))
+        }
+        url if url == AcceptedMessageKeysFilter::type_url() => {
+            let filter: AcceptedMessageKeysFilter = any.to_msg()?;
+            (
+                "wasm/AcceptedMessageKeysFilter",
+                json!({ "keys": filter.keys }),
+            )
+        }
+        url if url == BasicAllowance::type_url() => {
+            let allowance: BasicAllowance = any.to_msg()?;
+            ("cosmos-sdk/BasicAllowance", basic_allowance(&allowance)?)
+        }
+        url if url == PeriodicAllowance::type_url() => {
+            let allowance: PeriodicAllowance = any.to_msg()?;
+            // The period reset is set by the chain, the zero time until then
+            let period_reset = allowance.period_reset.unwrap_or(Timestamp {
+                seconds: ZERO_TIME_SECONDS,
+                nanos: 0,
+            });
+            (
+                "cosmos-sdk/PeriodicAllowance",
+                json!({
+                    "basic": basic_allowance(&allowance.basic.unwrap_or_default())?,
+                    "period": duration(&allowance.period.unwrap_or_default()),
+                    "period_can_spend": proto_coins(&allowance.period_can_spend),
+                    "period_reset": timestamp(&period_reset)?,
+                    "period_spend_limit": proto_coins(&allowance.period_spend_limit),
+                }),
+            )
+        }
+        url if url == AllowedMsgAllowance::type_url() => {
+            let allowance: AllowedMsgAllowance = any.to_msg()?;
+            let mut value = json!({ "allowed_messages": allowance.allowed_messages });
+            if let Some(inner) = &allowance.allowance {
+                value["allowance"] = amino_any(inner)?;
+            }
+            ("cosmos-sdk/AllowedMsgAllowance", value)
+        }
+        _ => {
+            return Err(RwaError::Encoding(format!(
+                "type {} has no Amino JSON encoding",
+                any.type_url
+            )))
+        }
+    };
+
+    Ok(json!({ "type": amino_type, "value": value }))
+}
+
+/// Converts a `BasicAllowance` into the value of its Amino JSON form.
+fn basic_allowance(allowance: &BasicAllowance) -> Result<Value, RwaError> {
+    let mut value = json!({ "spend_limit": proto_coins(&allowance.spend_limit) });
+    if let Some(expiration) = &allowance.expiration {
+        value["expiration"] = timestamp(expiration)?.into();
+    }
+    Ok(value)
+}
+
+/// Formats a duration as its number of nanoseconds, as Amino JSON does.
+fn duration(duration: &Duration) -> String {
+    (i128::from(duration.seconds) * 1_000_000_000 + i128::from(duration.nanos)).to_string()
+}
+
+/// Formats a timestamp as Go's `time.RFC3339Nano`, as Amino JSON does.
+fn timestamp(timestamp: &Timestamp) -> Result<String, RwaError> {
+    let time = Time::from_unix_timestamp(timestamp.seconds, timestamp.nanos as u32)
+        .map_err(|e| RwaError::Encoding(format!("invalid timestamp: {}", e)))?;
+    Ok(time.to_rfc3339())
+}
+
+fn proto_coins(coins: &[ProtoCoin]) -> Vec<Value> {
+    coins
+        .iter()
+        .map(|coin| json!({ "amount": coin.amount, "denom": coin.denom }))
+        .collect()
+}
+
+fn coins(coins: &[Coin]) -> Vec<Value> {
+    coins
+        .iter()
+        .map(|coin| json!({ "amount": coin.amount.to_string(), "denom": coin.denom.to_string() }))
+        .collect()
+}
@@ -0,0 +1,339 @@
+//! Authz delegation for the RWA SDK.
+//!
+//! ERC-3643 agents, such as a transfer agent or a KYC provider, act for the
+//! issuer without holding its key: the issuer grants them an `authz`
+//! authorization to call the token, identity and compliance contracts, and
+//! the agents send the issuer's operations wrapped in a `MsgExec`.
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//!
+//! use erc3643sdk::authz::request::GrantRequest;
+//! use erc3643sdk::authz::ContractLimit;
+//! use erc3643sdk::token::request::TransferMessageRequest;
+//! use erc3643sdk::{GasSetting, LocalSigner, RwaClient};
+//!
+//! # async fn run(client: RwaClient, issuer: Arc<LocalSigner>, agent: Arc<LocalSigner>) -> Result<(), erc3643sdk::RwaError> {
+//! // The issuer grants the agent up to 100 contract calls
+//! client
+//!     .grant(GrantRequest {
+//!         from: "kii1issuer...".parse()?,
+//!         grantee: "kii1agent...".parse()?,
+//!         authorization: client.contract_authorization(ContractLimit::MaxCalls(100)),
+//!         expiration: None,
+//!         signer: issuer,
+//!         gas_limit: GasSetting::default(),
+//!     })
+//!     .await?;
+//!
+//! // The agent sends operations of the issuer, signed with its own key
+//! let agent_client = client.clone().with_authz_exec(true);
+//! agent_client
+//!     .transfer(TransferMessageRequest {
+//!         from: "kii1issuer...".parse()?,
+//!         to: "kii1investor...".parse()?,
+//!         amount: 1_000,
+//!         signer: agent,
+//!         gas_limit: GasSetting::default(),
+//!     })
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::SystemTime;
+
+use cosmrs::proto::cosmos::authz::v1beta1::{
+    GenericAuthorization, Grant, MsgExec, MsgGrant, MsgRevoke,
+};
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    AcceptedMessageKeysFilter, AllowAllMessagesFilter, CombinedLimit,
+    ContractExecutionAuthorization, ContractGrant as ProtoContractGrant, MaxCallsLimit,
+    MaxFundsLimit, MsgExecuteContract,
+};
+use cosmrs::proto::prost::Name;
+use cosmrs::{Any, Coin};
+use request::{GrantRequest, RevokeRequest};
+
+use crate::{Address, ExecuteResponse, RwaClient, RwaError, TxBatch};
+
+pub mod request;
+
+/// The contract calls an agent is allowed to send for the granter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Authorization {
+    /// A `GenericAuthorization` of `MsgExecuteContract`: any call to any
+    /// contract, without limit
+    Generic,
+    /// A `ContractExecutionAuthorization`: calls to the given contracts only
+    ContractExecution(Vec<ContractGrant>),
+}
+
+/// The calls an agent is allowed to send to one contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractGrant {
+    /// The address of the contract, in bech32 or `0x` form
+    pub contract: Address,
+    /// How much the agent may use the grant
+    pub limit: ContractLimit,
+    /// The top-level keys of the messages allowed, e.g. `transfer`; any
+    /// message is allowed if empty
+    pub messages: Vec<String>,
+}
+
+/// The usage limit of a [`ContractGrant`], decreased by every call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContractLimit {
+    /// A number of calls
+    MaxCalls(u64),
+    /// A total of funds sent along the calls
+    MaxFunds(Vec<Coin>),
+    /// A number of calls and a total of funds, whichever is exhausted first
+    Combined { calls: u64, funds: Vec<Coin> },
+}
+
+impl ContractGrant {
+    /// Creates a grant allowing any message to `contract` up to `limit`.
+    pub fn new(contract: &Address, limit: ContractLimit) -> Self {
+        Self {
+            contract: contract.clone(),
+            limit,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Restricts the grant to messages with the given top-level keys.
+    pub fn messages(mut self, messages: &[&str]) -> Self {
+        self.messages = messages.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Builds the grant stored on chain, with the contract address in the
+    /// bech32 form of the chain's `hrp`.
+    fn to_proto(&self, hrp: &str) -> Result<ProtoContractGrant, RwaError> {
+        let limit = match &self.limit {
+            ContractLimit::MaxCalls(calls) => Any::from_msg(&MaxCallsLimit { remaining: *calls }),
+            ContractLimit::MaxFunds(funds) => Any::from_msg(&MaxFundsLimit {
+                amounts: funds.iter().cloned().map(Into::into).collect(),
+            }),
+            ContractLimit::Combined { calls, funds } => Any::from_msg(&CombinedLimit {
+                calls_remaining: *calls,
+                amounts: funds.iter().cloned().map(Into::into).collect(),
+            }),
+        }?;
+        let filter = if self.messages.is_empty() {
+            Any::from_msg(&AllowAllMessagesFilter {})
+        } else {
+            Any::from_msg(&AcceptedMessageKeysFilter {
+                keys: self.messages.clone(),
+            })
+        }?;
+
+        Ok(ProtoContractGrant {
+            contract: self.contract.on_chain(hrp)?.to_string(),
+            limit: Some(limit),
+            filter: Some(filter),
+        })
+    }
+}
+
+impl Authorization {
+    /// Packs the authorization into the `Any` stored in a grant, with the
+    /// contract addresses in the bech32 form of the chain's `hrp`.
+    fn to_any(&self, hrp: &str) -> Result<Any, RwaError> {
+        let authorization = match self {
+            Authorization::Generic => Any::from_msg(&GenericAuthorization {
+                msg: MsgExecuteContract::type_url(),
+            }),
+            Authorization::ContractExecution(grants) => {
+                if grants.is_empty() {
+                    return Err(RwaError::Config(
+                        "a contract execution authorization needs at least one contract"
+                            .to_string(),
+                    ));
+                }
+                Any::from_msg(&ContractExecutionAuthorization {
+                    grants: grants
+                        .iter()
+                        .map(|grant| grant.to_proto(hrp))
+                        .collect::<Result<_, _>>()?,
+                })
+            }
+        }?;
+        Ok(authorization)
+    }
+}
+
+impl RwaClient {
+    /// Returns an authorization to call the token, identity and compliance
+    /// contracts of the client, each up to `limit`.
+    pub fn contract_authorization(&self, limit: ContractLimit) -> Authorization {
+        Authorization::ContractExecution(
+            [
+                &self.token_address,
+                &self.identity_address,
+                &self.compliance_address,
+            ]
+            .into_iter()
+            .map(|contract| {
+                let contract = Address::from_bech32(contract)
+                    .expect("contract addresses are validated by the builder");
+                ContractGrant::new(&contract, limit.clone())
+            })
+            .collect(),
+        )
+    }
+
+    /// Sends the operations of the `from` account through `MsgExec`, signed by
+    /// an agent it granted an authorization to.
+    ///
+    /// When enabled, the signer of every operation is the grantee: its
+    /// messages keep `from` as their sender and are wrapped in a `MsgExec`
+    /// sent, and paid for, by the signer's account. Operations signed by the
+    /// `from` account itself are sent as is. A batch is wrapped in a single
+    /// `MsgExec`, and its result still holds one result per batch message.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether operations are sent through `MsgExec`
+    pub fn with_authz_exec(mut self, enabled: bool) -> Self {
+        self.authz_exec = enabled;
+        self
+    }
+
+    /// Grants an agent the right to call contracts for the `from` account.
+    ///
+    /// A new grant replaces the previous grant of the same agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `GrantRequest` containing:
+    ///   - `from`: The granter, whose operations the agent may send
+    ///   - `grantee`: The address of the agent
+    ///   - `authorization`: The contract calls allowed, see
+    ///     [`RwaClient::contract_authorization`]
+    ///   - `expiration`: When the grant expires, never if `None`
+    ///   - `signer`: The signing key of the granter
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn grant(&self, request: GrantRequest) -> Result<ExecuteResponse, RwaError> {
+        let msg = grant_msg(
+            self.account_id(&request.from)?.as_ref(),
+            self.account_id(&request.grantee)?.as_ref(),
+            &request.authorization,
+            request.expiration,
+            &self.account_prefix,
+        )?;
+        self.send(
+            &request.from,
+            vec![msg],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Revokes the contract calls granted to an agent by the `from` account.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `RevokeRequest` containing:
+    ///   - `from`: The granter
+    ///   - `grantee`: The address of the agent
+    ///   - `signer`: The signing key of the granter
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn revoke(&self, request: RevokeRequest) -> Result<ExecuteResponse, RwaError> {
+        let msg = revoke_msg(
+            self.account_id(&request.from)?.as_ref(),
+            self.account_id(&request.grantee)?.as_ref(),
+        )?;
+        self.send(
+            &request.from,
+            vec![msg],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+}
+
+impl TxBatch<'_> {
+    /// Adds a grant of the right to call contracts for the batch sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `grantee` - The address of the agent
+    /// * `authorization` - The contract calls allowed
+    /// * `expiration` - When the grant expires, never if `None`
+    pub fn grant(
+        mut self,
+        grantee: &Address,
+        authorization: &Authorization,
+        expiration: Option<SystemTime>,
+    ) -> Self {
+        let grantee = self.account(grantee);
+        let msg = grant_msg(
+            self.from(),
+            &grantee,
+            authorization,
+            expiration,
+            &self.client.account_prefix,
+        );
+        self.push_message(msg)
+    }
+
+    /// Adds the revocation of the contract calls granted to an agent.
+    ///
+    /// # Arguments
+    ///
+    /// * `grantee` - The address of the agent
+    pub fn revoke(mut self, grantee: &Address) -> Self {
+        let grantee = self.account(grantee);
+        let msg = revoke_msg(self.from(), &grantee);
+        self.push_message(msg)
+    }
+}
+
+/// Wraps messages sent by a granter into a `MsgExec` sent by `grantee`.
+pub(crate) fn exec_msg(grantee: &str, msgs: Vec<Any>) -> Result<Any, RwaError> {
+    Ok(Any::from_msg(&MsgExec {
+        grantee: grantee.to_string(),
+        msgs,
+    })?)
+}
+
+/// Builds a `MsgGrant`, with the contract addresses of the authorization in
+/// the bech32 form of the chain's `hrp`.
+fn grant_msg(
+    granter: &str,
+    grantee: &str,
+    authorization: &Authorization,
+    expiration: Option<SystemTime>,
+    hrp: &str,
+) -> Result<Any, RwaError> {
+    Ok(Any::from_msg(&MsgGrant {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+        grant: Some(Grant {
+            authorization: Some(authorization.to_any(hrp)?),
+            expiration: expiration.map(Into::into),
+        }),
+    })?)
+}
+
+/// Builds a `MsgRevoke` of `MsgExecuteContract`, which both kinds of
+/// [`Authorization`] authorize.
+fn revoke_msg(granter: &str, grantee: &str) -> Result<Any, RwaError> {
+    Ok(Any::from_msg(&MsgRevoke {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+        msg_type_url: MsgExecuteContract::type_url(),
+    })?)
+}
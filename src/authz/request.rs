@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use super::Authorization;
+use crate::{Address, GasSetting, Signer};
+
+/// Request structure for granting an agent the right to act for the granter
+pub struct GrantRequest {
+    pub from: Address,
+    pub grantee: Address,
+    pub authorization: Authorization,
+    pub expiration: Option<SystemTime>,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for revoking the contract calls granted to an agent
+pub struct RevokeRequest {
+    pub from: Address,
+    pub grantee: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
@@ -0,0 +1,535 @@
+//! Multi-message transactions for the RWA SDK.
+//!
+//! A [`TxBatch`] collects token, identity and compliance operations sent by one
+//! account and signs them into a single transaction paying a single fee. The
+//! messages execute atomically: if one of them fails, none is applied.
+//!
+//! ```rust,no_run
+//! use erc3643sdk::{GasSetting, LocalSigner, RwaClient};
+//!
+//! # async fn onboard(client: RwaClient, signer: LocalSigner) -> Result<(), erc3643sdk::RwaError> {
+//! let response = client
+//!     .batch(&"kii1issuer...".parse()?)
+//!     .add_compliance_module("Country Restriction", &"kii1module...".parse()?)
+//!     .transfer(&"kii1investor...".parse()?, 1_000)
+//!     .execute(&signer, GasSetting::default())
+//!     .await?;
+//! println!("{} messages in {}", response.messages.len(), response.tx.tx_hash);
+//! # Ok(())
+//! # }
+//! ```
+
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::authz::v1beta1::{MsgExec, MsgExecResponse};
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxMsgData;
+use cosmrs::proto::cosmwasm::wasm::v1::{MsgExecuteContract, MsgExecuteContractResponse};
+use cosmrs::proto::prost::{Message, Name};
+use cosmrs::tendermint::abci::Event;
+use cosmrs::tx::Body;
+use cosmrs::{AccountId, Any, Coin};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::{
+    execute_contract_msg, Address, ExecuteResponse, FeeOptions, GasSetting, KeyType,
+    MultisigAccount, RwaClient, RwaError, SignMode, Signer, TxKey, TxSigners, UnsignedTx,
+};
+
+/// Event attribute the Cosmos SDK uses to tie an event to a message.
+const MSG_INDEX_ATTRIBUTE: &str = "msg_index";
+
+/// Event attribute the authz module uses to tie an event to a message of a `MsgExec`.
+const AUTHZ_MSG_INDEX_ATTRIBUTE: &str = "authz_msg_index";
+
+/// A batch of operations signed into a single atomic transaction.
+///
+/// Created with [`RwaClient::batch`]. Operations are added in execution order
+/// by the methods of the token, identity and compliance modules, or as raw
+/// messages with [`TxBatch::message`].
+#[derive(Debug)]
+pub struct TxBatch<'a> {
+    pub(crate) client: &'a RwaClient,
+    from: String,
+    messages: Vec<Any>,
+    sign_mode: Option<SignMode>,
+    key_type: KeyType,
+    fee_granter: Option<String>,
+    fee_payer: Option<&'a dyn Signer>,
+    check_paused: bool,
+    error: Option<RwaError>,
+}
+
+/// The result of a batch transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResponse {
+    /// The result of the transaction as a whole
+    pub tx: ExecuteResponse,
+    /// The result of each message in batch order, empty until the transaction is committed
+    pub messages: Vec<MessageResult>,
+}
+
+/// The result of a single message of a committed transaction.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MessageResult {
+    /// The data returned by the message, e.g. the response data of a contract
+    pub data: Vec<u8>,
+    /// The events emitted by the message
+    pub events: Vec<Event>,
+}
+
+impl RwaClient {
+    /// Starts a batch of operations sent by `from` in a single transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The address initiating and paying for the transaction
+    pub fn batch(&self, from: &Address) -> TxBatch<'_> {
+        let mut batch = TxBatch {
+            client: self,
+            from: String::new(),
+            messages: Vec::new(),
+            sign_mode: None,
+            key_type: KeyType::default(),
+            fee_granter: None,
+            fee_payer: None,
+            check_paused: false,
+            error: None,
+        };
+        batch.from = batch.account(from);
+        batch
+    }
+}
+
+impl<'a> TxBatch<'a> {
+    /// Adds an arbitrary message, e.g. a bank send, to the batch.
+    pub fn message(mut self, msg: Any) -> Self {
+        self.messages.push(msg);
+        self
+    }
+
+    /// Sets the sign mode of the transaction.
+    ///
+    /// By default the transaction is signed in the sign mode of its signer, or
+    /// in `SignMode::Direct` when built for offline signing.
+    pub fn sign_mode(mut self, sign_mode: SignMode) -> Self {
+        self.sign_mode = Some(sign_mode);
+        self
+    }
+
+    /// Sets the key type of the offline signer of [`TxBatch::build_unsigned`].
+    ///
+    /// By default the offline signer holds a Cosmos `secp256k1` key; the
+    /// signers of [`TxBatch::execute`] declare their own key type.
+    pub fn key_type(mut self, key_type: KeyType) -> Self {
+        self.key_type = key_type;
+        self
+    }
+
+    /// Sets the account whose fee allowance pays the fee of the transaction,
+    /// instead of the client's fee granter.
+    pub fn fee_granter(mut self, granter: &Address) -> Self {
+        self.fee_granter = Some(self.account(granter));
+        self
+    }
+
+    /// Sets the account paying the fee of the transaction, which co-signs it.
+    ///
+    /// A fee payer is only supported by [`TxBatch::execute`] and
+    /// [`TxBatch::execute_multisig`].
+    pub fn fee_payer(mut self, payer: &'a dyn Signer) -> Self {
+        self.fee_payer = Some(payer);
+        self
+    }
+
+    /// Returns the number of messages in the batch.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns `true` if no message was added to the batch.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Adds a contract call sent by the batch sender.
+    ///
+    /// An encoding failure is kept until the batch is executed, so operations
+    /// can be chained.
+    pub(crate) fn execute_contract<T: Serialize>(
+        self,
+        contract: &str,
+        msg: &T,
+        funds: Vec<Coin>,
+    ) -> Self {
+        let msg = execute_contract_msg(&self.from, contract, msg, funds);
+        self.push_message(msg)
+    }
+
+    /// Returns the address sending the operations of the batch.
+    pub(crate) fn from(&self) -> &str {
+        &self.from
+    }
+
+    /// Returns the bech32 form of an address on the client's chain, keeping
+    /// an invalid address as a failure until the batch is executed.
+    pub(crate) fn account(&mut self, address: &Address) -> String {
+        match self.client.account_id(address) {
+            Ok(account_id) => account_id.to_string(),
+            Err(e) => {
+                self.error.get_or_insert(e);
+                address.to_string()
+            }
+        }
+    }
+
+    /// Marks the batch as failing while the token is paused, so that it is
+    /// checked before broadcasting.
+    ///
+    /// The batch also fails if the `paused` query itself fails.
+    pub(crate) fn when_not_paused(mut self) -> Self {
+        self.check_paused = true;
+        self
+    }
+
+    /// Adds a message built by an operation, keeping its failure until the
+    /// batch is executed.
+    pub(crate) fn push_message(mut self, msg: Result<Any, RwaError>) -> Self {
+        if self.error.is_none() {
+            match msg {
+                Ok(msg) => self.messages.push(msg),
+                Err(e) => self.error = Some(e),
+            }
+        }
+        self
+    }
+
+    /// Signs the batch into a single transaction and broadcasts it.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - The signer of the batch sender
+    /// * `gas` - A fixed gas limit for the whole batch, or automatic estimation
+    ///   through simulation
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the BatchResponse, or an error. A failing message
+    /// fails the whole transaction with `RwaError::DeliverTx`, whose contract
+    /// error names the message index. A batch transferring or minting tokens
+    /// fails with `RwaError::TokenPaused` without being broadcast while the
+    /// token is paused.
+    pub async fn execute(
+        self,
+        signer: &dyn Signer,
+        gas: GasSetting,
+    ) -> Result<BatchResponse, RwaError> {
+        let client = self.client;
+        if self.check_paused {
+            client.ensure_not_paused().await?;
+        }
+        let sign_mode = self.sign_mode.unwrap_or_else(|| signer.sign_mode());
+        let signers = TxSigners::Single(signer, sign_mode);
+        let fee = self.fee_options()?;
+        let from = self.from.clone();
+        let (sender, tx_body) = self.into_tx_body(&signers.key())?;
+        let exec_type_urls = exec_type_urls(&from, &sender, &tx_body)?;
+
+        let tx = client
+            .sign_and_broadcast(&sender, tx_body, &signers, &fee, gas)
+            .await?;
+        let messages = tx.batch_message_results(exec_type_urls.as_deref())?;
+        Ok(BatchResponse { tx, messages })
+    }
+
+    /// Signs the batch sent by a multisig account with several of its members
+    /// and broadcasts it.
+    ///
+    /// # Arguments
+    ///
+    /// * `multisig` - The multisig account sending the batch
+    /// * `signers` - The signers of at least `threshold` members of the account
+    /// * `gas` - A fixed gas limit for the whole batch, or automatic estimation
+    ///   through simulation
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the BatchResponse, or an error, e.g.
+    /// `RwaError::TokenPaused` as for [`TxBatch::execute`]
+    pub async fn execute_multisig(
+        self,
+        multisig: &MultisigAccount,
+        signers: &[&dyn Signer],
+        gas: GasSetting,
+    ) -> Result<BatchResponse, RwaError> {
+        let client = self.client;
+        if self.check_paused {
+            client.ensure_not_paused().await?;
+        }
+        let fee = self.fee_options()?;
+        let from = self.from.clone();
+        let (sender, tx_body) = self.into_multisig_tx_body(multisig)?;
+        let exec_type_urls = exec_type_urls(&from, &sender, &tx_body)?;
+
+        let signers = TxSigners::Multisig(multisig, signers);
+        let tx = client
+            .sign_and_broadcast(&sender, tx_body, &signers, &fee, gas)
+            .await?;
+        let messages = tx.batch_message_results(exec_type_urls.as_deref())?;
+        Ok(BatchResponse { tx, messages })
+    }
+
+    /// Builds the batch into a transaction to be signed offline.
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key` - The public key of the offline signer of the batch sender
+    /// * `gas` - A fixed gas limit for the whole batch, or automatic estimation
+    ///   through simulation
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the UnsignedTx, to be signed with
+    /// [`UnsignedTx::sign`] and broadcast with [`RwaClient::broadcast_signed`],
+    /// or an error, e.g. `RwaError::TokenPaused` as for [`TxBatch::execute`]
+    pub async fn build_unsigned(
+        self,
+        public_key: PublicKey,
+        gas: GasSetting,
+    ) -> Result<UnsignedTx, RwaError> {
+        let client = self.client;
+        if self.check_paused {
+            client.ensure_not_paused().await?;
+        }
+        let key = TxKey::Single {
+            public_key,
+            key_type: self.key_type,
+            sign_mode: self.sign_mode.unwrap_or_default(),
+        };
+        let fee = self.offline_fee_options()?;
+        let (sender, tx_body) = self.into_tx_body(&key)?;
+
+        client
+            .build_unsigned(&sender, tx_body, &key, fee.granter.as_ref(), gas)
+            .await
+    }
+
+    /// Builds the batch sent by a multisig account into a transaction whose
+    /// members sign offline.
+    ///
+    /// # Arguments
+    ///
+    /// * `multisig` - The multisig account sending the batch
+    /// * `gas` - A fixed gas limit for the whole batch, or automatic estimation
+    ///   through simulation
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the UnsignedTx, to be signed by the members with
+    /// [`UnsignedTx::sign_partial`] and combined with [`UnsignedTx::combine`],
+    /// or an error, e.g. `RwaError::TokenPaused` as for [`TxBatch::execute`]
+    pub async fn build_multisig(
+        self,
+        multisig: &MultisigAccount,
+        gas: GasSetting,
+    ) -> Result<UnsignedTx, RwaError> {
+        let client = self.client;
+        if self.check_paused {
+            client.ensure_not_paused().await?;
+        }
+        let fee = self.offline_fee_options()?;
+        let (sender, tx_body) = self.into_multisig_tx_body(multisig)?;
+
+        let key = TxKey::Multisig(multisig.clone());
+        client
+            .build_unsigned(&sender, tx_body, &key, fee.granter.as_ref(), gas)
+            .await
+    }
+
+    /// Returns the fee granter and payer of the batch transaction.
+    fn fee_options(&self) -> Result<FeeOptions<'a>, RwaError> {
+        let granter = self
+            .fee_granter
+            .as_deref()
+            .map(|granter| {
+                AccountId::from_str(granter).map_err(|e| RwaError::invalid_address(granter, e))
+            })
+            .transpose()?;
+        Ok(FeeOptions {
+            granter,
+            payer: self.fee_payer,
+        })
+    }
+
+    /// Returns the fee granter of a batch transaction signed offline.
+    fn offline_fee_options(&self) -> Result<FeeOptions<'a>, RwaError> {
+        if self.fee_payer.is_some() {
+            return Err(RwaError::Config(
+                "a fee payer is not supported for transactions signed offline".to_string(),
+            ));
+        }
+        self.fee_options()
+    }
+
+    /// Returns the sender and the body of a batch sent by a multisig account.
+    fn into_multisig_tx_body(
+        self,
+        multisig: &MultisigAccount,
+    ) -> Result<(AccountId, Body), RwaError> {
+        if self.sign_mode == Some(SignMode::Direct) {
+            return Err(RwaError::Config(
+                "multisig members sign in the Amino JSON sign mode".to_string(),
+            ));
+        }
+
+        let (sender, tx_body) = self.into_tx_body(&TxKey::Multisig(multisig.clone()))?;
+        if multisig.account_id(sender.prefix())? != sender {
+            return Err(RwaError::Config(format!(
+                "{} is not the address of the multisig account",
+                sender
+            )));
+        }
+        Ok((sender, tx_body))
+    }
+
+    /// Returns the sender and the body of the batch transaction signed with `key`.
+    fn into_tx_body(self, key: &TxKey) -> Result<(AccountId, Body), RwaError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if self.messages.is_empty() {
+            return Err(RwaError::Config(
+                "a transaction batch needs at least one message".to_string(),
+            ));
+        }
+
+        let from = AccountId::from_str(&self.from)
+            .map_err(|e| RwaError::invalid_address(&self.from, e))?;
+        self.client.tx_body(&from, self.messages, key)
+    }
+}
+
+impl ExecuteResponse {
+    /// Splits the result of a committed transaction per message.
+    ///
+    /// The data of each message is decoded from the transaction's message
+    /// responses, and events are assigned to messages through their
+    /// `msg_index` attribute. Events of the transaction itself, such as the
+    /// fee payment, belong to no message.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the results in message order, empty if the
+    /// transaction is not committed yet, or an error if the data is malformed
+    pub fn message_results(&self) -> Result<Vec<MessageResult>, RwaError> {
+        let msg_data = TxMsgData::decode(self.data.as_slice())?;
+        let results = msg_data
+            .msg_responses
+            .into_iter()
+            .map(|response| {
+                let is_contract_call = response.type_url == MsgExecuteContractResponse::type_url();
+                message_result(is_contract_call, response.value)
+            })
+            .collect::<Result<Vec<_>, RwaError>>()?;
+
+        Ok(assign_events(results, &self.events, MSG_INDEX_ATTRIBUTE))
+    }
+
+    /// Splits the result of a committed transaction made of a single
+    /// `MsgExec` per message the `MsgExec` executed.
+    ///
+    /// The data of each message is decoded from the `MsgExecResponse`, and
+    /// events are assigned to messages through the `authz_msg_index`
+    /// attribute the authz module adds to them.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_urls` - The type URLs of the executed messages, in order
+    fn exec_message_results(&self, type_urls: &[String]) -> Result<Vec<MessageResult>, RwaError> {
+        let msg_data = TxMsgData::decode(self.data.as_slice())?;
+        let Some(response) = msg_data.msg_responses.into_iter().next() else {
+            return Ok(Vec::new());
+        };
+        let exec = MsgExecResponse::decode(response.value.as_slice())?;
+        let results = exec
+            .results
+            .into_iter()
+            .zip(type_urls)
+            .map(|(data, type_url)| {
+                message_result(*type_url == MsgExecuteContract::type_url(), data)
+            })
+            .collect::<Result<Vec<_>, RwaError>>()?;
+
+        Ok(assign_events(
+            results,
+            &self.events,
+            AUTHZ_MSG_INDEX_ATTRIBUTE,
+        ))
+    }
+
+    /// Splits the result of a batch transaction per batch message.
+    fn batch_message_results(
+        &self,
+        exec_type_urls: Option<&[String]>,
+    ) -> Result<Vec<MessageResult>, RwaError> {
+        match exec_type_urls {
+            Some(type_urls) => self.exec_message_results(type_urls),
+            None => self.message_results(),
+        }
+    }
+}
+
+/// Returns the type URLs of the batch messages if the transaction wraps them
+/// in a `MsgExec`, i.e. if it is sent by another account than the batch.
+fn exec_type_urls(
+    from: &str,
+    sender: &AccountId,
+    tx_body: &Body,
+) -> Result<Option<Vec<String>>, RwaError> {
+    if sender.as_ref() == from {
+        return Ok(None);
+    }
+    let exec: MsgExec = tx_body.messages[0].to_msg()?;
+    Ok(Some(
+        exec.msgs.into_iter().map(|msg| msg.type_url).collect(),
+    ))
+}
+
+/// Builds the result of a message from its response data.
+fn message_result(is_contract_call: bool, data: Vec<u8>) -> Result<MessageResult, RwaError> {
+    let data = if is_contract_call {
+        MsgExecuteContractResponse::decode(data.as_slice())?.data
+    } else {
+        data
+    };
+    Ok(MessageResult {
+        data,
+        events: Vec::new(),
+    })
+}
+
+/// Assigns events to the message results through their `index_attribute`.
+fn assign_events(
+    mut results: Vec<MessageResult>,
+    events: &[Event],
+    index_attribute: &str,
+) -> Vec<MessageResult> {
+    for event in events {
+        let Some(index) = msg_index(event, index_attribute) else {
+            continue;
+        };
+        if index >= results.len() {
+            results.resize_with(index + 1, MessageResult::default);
+        }
+        results[index].events.push(event.clone());
+    }
+    results
+}
+
+/// Returns the index of the message that emitted `event`, if any.
+fn msg_index(event: &Event, index_attribute: &str) -> Option<usize> {
+    event
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key_bytes() == index_attribute.as_bytes())
+        .and_then(|attribute| attribute.value_str().ok()?.parse().ok())
+}
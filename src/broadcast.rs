@@ -0,0 +1,59 @@
+//! Broadcast modes for the RWA SDK.
+//!
+//! By default [`RwaClient`](crate::RwaClient) waits for each transaction to be
+//! committed with `broadcast_tx_commit`. That single RPC call blocks until the
+//! next block, times out under load and is deprecated on newer CometBFT nodes,
+//! so the client can instead return as soon as the node accepted the
+//! transaction and confirm it later with
+//! [`RwaClient::wait_for_tx`](crate::RwaClient::wait_for_tx).
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::RwaError;
+
+/// Default interval between two transaction lookups while waiting for inclusion.
+pub const DEFAULT_TX_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How transactions are handed to the node.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BroadcastMode {
+    /// Wait until the transaction is committed in a block.
+    #[default]
+    Commit,
+    /// Return once the transaction passed `CheckTx`.
+    Sync,
+    /// Return as soon as the node received the transaction.
+    Async,
+}
+
+impl FromStr for BroadcastMode {
+    type Err = RwaError;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode.to_ascii_lowercase().as_str() {
+            "commit" | "block" => Ok(BroadcastMode::Commit),
+            "sync" => Ok(BroadcastMode::Sync),
+            "async" => Ok(BroadcastMode::Async),
+            _ => Err(RwaError::Config(format!(
+                "invalid broadcast mode `{}`, expected commit, sync or async",
+                mode
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for BroadcastMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self {
+            BroadcastMode::Commit => "commit",
+            BroadcastMode::Sync => "sync",
+            BroadcastMode::Async => "async",
+        };
+        f.write_str(mode)
+    }
+}
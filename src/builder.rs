@@ -0,0 +1,422 @@
+//! Construction of [`RwaClient`] instances.
+//!
+//! [`RwaClientBuilder`] collects the client settings through named setters,
+//! a network profile file (TOML or JSON) or `RWA_*` environment variables,
+//! and validates them before the client is built.
+//!
+//! A profile file contains the fields of [`NetworkConfig`]:
+//!
+//! ```toml
+//! rpc_url = "https://rpc.testnet.example.com:443"
+//! chain_id = "rwa-testnet-1"
+//! token_address = "kii1..."
+//! identity_address = "kii1..."
+//! compliance_address = "kii1..."
+//! gas_prices = "0.025ukii"
+//! ```
+//!
+//! Several networks can share one file as named tables (`[mainnet]`,
+//! `[testnet]`, ...) loaded with [`RwaClientBuilder::from_profile`].
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use cosmrs::proto::cosmos::auth::v1beta1::BaseAccount;
+use cosmrs::proto::prost::Message;
+use cosmrs::rpc::HttpClient;
+use cosmrs::tendermint::chain::Id;
+use cosmrs::{AccountId, Gas};
+use serde::{Deserialize, Serialize};
+
+use crate::broadcast::DEFAULT_TX_POLL_INTERVAL;
+use crate::transport::Transport;
+use crate::{AccountTypes, BroadcastMode, GasConfig, GasPrice, RwaClient, RwaError};
+
+/// RPC endpoint used when none is configured.
+pub const DEFAULT_RPC_URL: &str = "http://localhost:26657";
+
+/// Prefix of the environment variables read by [`RwaClientBuilder::from_env`].
+pub const ENV_PREFIX: &str = "RWA_";
+
+/// The settings of a network profile, as loaded from a file or the environment.
+///
+/// Every field is optional so that profiles can be layered, e.g. a file
+/// overridden by environment variables.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// The URL of the RPC endpoint
+    pub rpc_url: Option<String>,
+    /// The ID of the blockchain
+    pub chain_id: Option<String>,
+    /// The address of the token contract
+    pub token_address: Option<String>,
+    /// The address of the identity contract
+    pub identity_address: Option<String>,
+    /// The address of the compliance contract
+    pub compliance_address: Option<String>,
+    /// The accepted gas prices in order of preference, e.g. `0.025ukii,0.1uusdc`
+    pub gas_prices: Option<String>,
+    /// Whether to raise the gas price to the node's minimum before pricing fees
+    pub query_min_gas_price: Option<bool>,
+    /// The minimum gas limit of an estimated transaction
+    pub gas_floor: Option<Gas>,
+    /// The maximum gas limit of an estimated transaction
+    pub gas_ceiling: Option<Gas>,
+    /// How transactions are handed to the node: `commit`, `sync` or `async`
+    pub broadcast_mode: Option<BroadcastMode>,
+    /// The account whose fee allowance pays the fees of every transaction
+    pub fee_granter: Option<String>,
+    /// The bech32 prefix of account addresses, e.g. `kii`
+    pub account_prefix: Option<String>,
+}
+
+impl NetworkConfig {
+    /// Overrides the settings of `self` with those present in `other`.
+    fn merge(&mut self, other: NetworkConfig) {
+        self.rpc_url = other.rpc_url.or(self.rpc_url.take());
+        self.chain_id = other.chain_id.or(self.chain_id.take());
+        self.token_address = other.token_address.or(self.token_address.take());
+        self.identity_address = other.identity_address.or(self.identity_address.take());
+        self.compliance_address = other.compliance_address.or(self.compliance_address.take());
+        self.gas_prices = other.gas_prices.or(self.gas_prices.take());
+        self.query_min_gas_price = other.query_min_gas_price.or(self.query_min_gas_price);
+        self.gas_floor = other.gas_floor.or(self.gas_floor);
+        self.gas_ceiling = other.gas_ceiling.or(self.gas_ceiling);
+        self.broadcast_mode = other.broadcast_mode.or(self.broadcast_mode);
+        self.fee_granter = other.fee_granter.or(self.fee_granter.take());
+        self.account_prefix = other.account_prefix.or(self.account_prefix.take());
+    }
+}
+
+/// Builder for [`RwaClient`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use erc3643sdk::RwaClient;
+///
+/// # fn main() -> Result<(), erc3643sdk::RwaError> {
+/// let client = RwaClient::builder()
+///     .rpc_url("http://rpc.example.com:26657")
+///     .chain_id("my-chain-id")
+///     .token_address("cosmos1...")
+///     .identity_address("cosmos1...")
+///     .compliance_address("cosmos1...")
+///     .gas_prices("0.025ukii")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RwaClientBuilder {
+    config: NetworkConfig,
+    transport: Option<Arc<dyn Transport>>,
+    tx_poll_interval: Option<Duration>,
+    account_types: AccountTypes,
+}
+
+impl RwaClientBuilder {
+    /// Creates a builder with no settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder from a network profile file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `.toml` or `.json` file containing a `NetworkConfig`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, RwaError> {
+        let config: NetworkConfig = read_config(path.as_ref())?;
+        Ok(Self::new().config(config))
+    }
+
+    /// Creates a builder from a named profile in a file of profiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - A `.toml` or `.json` file mapping profile names to `NetworkConfig`s
+    /// * `profile` - The name of the profile to load, e.g. `testnet`
+    pub fn from_profile(path: impl AsRef<Path>, profile: &str) -> Result<Self, RwaError> {
+        let path = path.as_ref();
+        let mut profiles: HashMap<String, NetworkConfig> = read_config(path)?;
+        let config = profiles.remove(profile).ok_or_else(|| {
+            RwaError::Config(format!(
+                "profile `{}` not found in {}",
+                profile,
+                path.display()
+            ))
+        })?;
+        Ok(Self::new().config(config))
+    }
+
+    /// Creates a builder from `RWA_*` environment variables.
+    ///
+    /// See [`RwaClientBuilder::with_env`] for the variables read.
+    pub fn from_env() -> Result<Self, RwaError> {
+        Self::new().with_env()
+    }
+
+    /// Overrides the current settings with `RWA_*` environment variables.
+    ///
+    /// The variables read are `RWA_RPC_URL`, `RWA_CHAIN_ID`, `RWA_TOKEN_ADDRESS`,
+    /// `RWA_IDENTITY_ADDRESS`, `RWA_COMPLIANCE_ADDRESS`, `RWA_GAS_PRICES`,
+    /// `RWA_QUERY_MIN_GAS_PRICE`, `RWA_GAS_FLOOR`, `RWA_GAS_CEILING`,
+    /// `RWA_BROADCAST_MODE`, `RWA_FEE_GRANTER` and `RWA_ACCOUNT_PREFIX`. Unset
+    /// variables leave the current settings untouched.
+    pub fn with_env(self) -> Result<Self, RwaError> {
+        fn parse_var<T: FromStr>(name: &str, value: Option<String>) -> Result<Option<T>, RwaError>
+        where
+            T::Err: std::fmt::Display,
+        {
+            value
+                .map(|value| {
+                    value.parse().map_err(|e| {
+                        RwaError::Config(format!(
+                            "invalid {}{} `{}`: {}",
+                            ENV_PREFIX, name, value, e
+                        ))
+                    })
+                })
+                .transpose()
+        }
+        let var = |name: &str| std::env::var(format!("{}{}", ENV_PREFIX, name)).ok();
+
+        let config = NetworkConfig {
+            rpc_url: var("RPC_URL"),
+            chain_id: var("CHAIN_ID"),
+            token_address: var("TOKEN_ADDRESS"),
+            identity_address: var("IDENTITY_ADDRESS"),
+            compliance_address: var("COMPLIANCE_ADDRESS"),
+            gas_prices: var("GAS_PRICES"),
+            query_min_gas_price: parse_var("QUERY_MIN_GAS_PRICE", var("QUERY_MIN_GAS_PRICE"))?,
+            gas_floor: parse_var("GAS_FLOOR", var("GAS_FLOOR"))?,
+            gas_ceiling: parse_var("GAS_CEILING", var("GAS_CEILING"))?,
+            broadcast_mode: parse_var("BROADCAST_MODE", var("BROADCAST_MODE"))?,
+            fee_granter: var("FEE_GRANTER"),
+            account_prefix: var("ACCOUNT_PREFIX"),
+        };
+        Ok(self.config(config))
+    }
+
+    /// Overrides the current settings with those present in `config`.
+    pub fn config(mut self, config: NetworkConfig) -> Self {
+        self.config.merge(config);
+        self
+    }
+
+    /// Sets the URL of the RPC endpoint. Defaults to `DEFAULT_RPC_URL`.
+    pub fn rpc_url(mut self, rpc_url: &str) -> Self {
+        self.config.rpc_url = Some(rpc_url.to_string());
+        self
+    }
+
+    /// Sets the ID of the blockchain.
+    pub fn chain_id(mut self, chain_id: &str) -> Self {
+        self.config.chain_id = Some(chain_id.to_string());
+        self
+    }
+
+    /// Sets the address of the token contract.
+    pub fn token_address(mut self, token_address: &str) -> Self {
+        self.config.token_address = Some(token_address.to_string());
+        self
+    }
+
+    /// Sets the address of the identity contract.
+    pub fn identity_address(mut self, identity_address: &str) -> Self {
+        self.config.identity_address = Some(identity_address.to_string());
+        self
+    }
+
+    /// Sets the address of the compliance contract.
+    pub fn compliance_address(mut self, compliance_address: &str) -> Self {
+        self.config.compliance_address = Some(compliance_address.to_string());
+        self
+    }
+
+    /// Sets the accepted gas prices, e.g. `0.025ukii` or `0.025ukii,0.1uusdc`.
+    ///
+    /// Fees are paid in the first denomination unless the node's minimum gas
+    /// prices are queried, see [`RwaClientBuilder::query_min_gas_price`].
+    pub fn gas_prices(mut self, gas_prices: &str) -> Self {
+        self.config.gas_prices = Some(gas_prices.to_string());
+        self
+    }
+
+    /// Sets whether the node's minimum gas prices are queried before pricing
+    /// each fee. The fee is then paid in the first configured denomination the
+    /// node accepts, at no less than the node's minimum. Defaults to `false`.
+    pub fn query_min_gas_price(mut self, query_min_gas_price: bool) -> Self {
+        self.config.query_min_gas_price = Some(query_min_gas_price);
+        self
+    }
+
+    /// Sets the bounds applied to automatically estimated gas limits.
+    /// Defaults to `GasConfig::default()`.
+    pub fn gas_config(mut self, gas_config: GasConfig) -> Self {
+        self.config.gas_floor = Some(gas_config.floor);
+        self.config.gas_ceiling = Some(gas_config.ceiling);
+        self
+    }
+
+    /// Sets how transactions are handed to the node. Defaults to
+    /// `BroadcastMode::Commit`.
+    pub fn broadcast_mode(mut self, broadcast_mode: BroadcastMode) -> Self {
+        self.config.broadcast_mode = Some(broadcast_mode);
+        self
+    }
+
+    /// Sets the account whose fee allowance pays the fees of every
+    /// transaction, e.g. the issuer paying the gas of its investors.
+    /// Defaults to none, the signer paying its own fees.
+    pub fn fee_granter(mut self, fee_granter: &str) -> Self {
+        self.config.fee_granter = Some(fee_granter.to_string());
+        self
+    }
+
+    /// Sets the bech32 prefix of account addresses, used to render the `0x`
+    /// addresses of requests. Defaults to the prefix of the token address.
+    pub fn account_prefix(mut self, account_prefix: &str) -> Self {
+        self.config.account_prefix = Some(account_prefix.to_string());
+        self
+    }
+
+    /// Sets the interval between two lookups of a transaction while waiting
+    /// for its inclusion. Defaults to `DEFAULT_TX_POLL_INTERVAL`.
+    pub fn tx_poll_interval(mut self, tx_poll_interval: Duration) -> Self {
+        self.tx_poll_interval = Some(tx_poll_interval);
+        self
+    }
+
+    /// Sets the transport used to reach the chain instead of an HTTP client
+    /// for the RPC url.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Registers an account type of the chain, in addition to the base,
+    /// module, vesting and `EthAccount` types known by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_url` - The type URL of the account
+    /// * `base_account` - Returns the base account of a decoded account, if it has one
+    pub fn account_type<M, F>(mut self, type_url: &str, base_account: F) -> Self
+    where
+        M: Message + Default + 'static,
+        F: Fn(M) -> Option<BaseAccount> + Send + Sync + 'static,
+    {
+        self.account_types.register(type_url, base_account);
+        self
+    }
+
+    /// Validates the settings and builds the client.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the RwaClient instance, or an error if a required
+    /// setting is missing or invalid
+    pub fn build(self) -> Result<RwaClient, RwaError> {
+        let config = self.config;
+
+        let chain_id = required(config.chain_id, "chain_id")?;
+        let chain_id = Id::from_str(&chain_id)
+            .map_err(|e| RwaError::Config(format!("invalid chain id `{}`: {}", chain_id, e)))?;
+
+        let token_address = validate_address(required(config.token_address, "token_address")?)?;
+        let identity_address =
+            validate_address(required(config.identity_address, "identity_address")?)?;
+        let compliance_address =
+            validate_address(required(config.compliance_address, "compliance_address")?)?;
+
+        let gas_prices = GasPrice::parse_list(&required(config.gas_prices, "gas_prices")?)?;
+        if gas_prices.is_empty() {
+            return Err(RwaError::Config("gas_prices is empty".to_string()));
+        }
+
+        let defaults = GasConfig::default();
+        let gas_config = GasConfig {
+            floor: config.gas_floor.unwrap_or(defaults.floor),
+            ceiling: config.gas_ceiling.unwrap_or(defaults.ceiling),
+        };
+        gas_config.validate()?;
+
+        let account_prefix = match config.account_prefix {
+            Some(account_prefix) => account_prefix,
+            None => AccountId::from_str(&token_address)
+                .map_err(|e| RwaError::invalid_address(&token_address, e))?
+                .prefix()
+                .to_string(),
+        };
+
+        let fee_granter = config
+            .fee_granter
+            .map(|granter| {
+                AccountId::from_str(&granter).map_err(|e| RwaError::invalid_address(&granter, e))
+            })
+            .transpose()?;
+
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => {
+                let rpc_url = config.rpc_url.as_deref().unwrap_or(DEFAULT_RPC_URL);
+                let rpc_client = HttpClient::new(rpc_url).map_err(|e| {
+                    RwaError::Config(format!("invalid rpc url `{}`: {}", rpc_url, e))
+                })?;
+                Arc::new(rpc_client)
+            }
+        };
+
+        Ok(RwaClient {
+            transport,
+            chain_id,
+            token_address,
+            identity_address,
+            compliance_address,
+            gas_prices,
+            query_min_gas_price: config.query_min_gas_price.unwrap_or(false),
+            gas_config,
+            broadcast_mode: config.broadcast_mode.unwrap_or_default(),
+            tx_poll_interval: self.tx_poll_interval.unwrap_or(DEFAULT_TX_POLL_INTERVAL),
+            sequences: Default::default(),
+            authz_exec: false,
+            fee_granter,
+            account_prefix,
+            account_types: Arc::new(self.account_types),
+        })
+    }
+}
+
+/// Returns the value of a required setting, or an error naming it.
+fn required<T>(value: Option<T>, name: &str) -> Result<T, RwaError> {
+    value.ok_or_else(|| RwaError::Config(format!("missing required setting `{}`", name)))
+}
+
+/// Checks that `address` is a valid bech32 account address.
+fn validate_address(address: String) -> Result<String, RwaError> {
+    AccountId::from_str(&address).map_err(|e| RwaError::invalid_address(&address, e))?;
+    Ok(address)
+}
+
+/// Reads a TOML or JSON file, depending on its extension.
+fn read_config<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, RwaError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| RwaError::Config(format!("failed to read {}: {}", path.display(), e)))?;
+    let parse_error =
+        |e: &dyn std::fmt::Display| RwaError::Config(format!("invalid {}: {}", path.display(), e));
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("toml") => toml::from_str(&contents).map_err(|e| parse_error(&e)),
+        Some("json") => serde_json::from_str(&contents).map_err(|e| parse_error(&e)),
+        _ => Err(RwaError::Config(format!(
+            "unsupported config file {}, expected a .toml or .json file",
+            path.display()
+        ))),
+    }
+}
@@ -6,7 +6,7 @@
 use request::ComplianceModuleRequest;
 use serde::{Deserialize, Serialize};
 
-use crate::{ExecuteResponse, RwaClient};
+use crate::{Address, ExecuteResponse, RwaClient, RwaError, TxBatch};
 
 pub mod request;
 
@@ -20,10 +20,10 @@ impl RwaClient {
         &self,
         module_name: &str,
         request: ComplianceModuleRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::AddComplianceModule {
             token_address: self.token_address.clone(),
-            module_address: request.module_addr,
+            module_address: self.account_id(&request.module_addr)?.to_string(),
             module_name: module_name.to_string(),
         };
 
@@ -32,7 +32,7 @@ impl RwaClient {
             &msg,
             self.compliance_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
@@ -46,10 +46,10 @@ impl RwaClient {
     pub async fn remove_compliance_module(
         &self,
         request: ComplianceModuleRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::RemoveComplianceModule {
             token_address: self.token_address.clone(),
-            module_address: request.module_addr,
+            module_address: self.account_id(&request.module_addr)?.to_string(),
         };
 
         self.execute(
@@ -57,7 +57,7 @@ impl RwaClient {
             &msg,
             self.compliance_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
@@ -73,10 +73,10 @@ impl RwaClient {
         &self,
         request: ComplianceModuleRequest,
         active: bool,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::UpdateComplianceModule {
             token_address: self.token_address.clone(),
-            module_address: request.module_addr,
+            module_address: self.account_id(&request.module_addr)?.to_string(),
             active,
         };
 
@@ -85,14 +85,63 @@ impl RwaClient {
             &msg,
             self.compliance_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
     }
 }
 
+impl TxBatch<'_> {
+    /// Adds the registration of a compliance module for the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `module_name` - The name of the module
+    /// * `module_addr` - The address of the module contract
+    pub fn add_compliance_module(mut self, module_name: &str, module_addr: &Address) -> Self {
+        let msg = ExecuteMsg::AddComplianceModule {
+            token_address: self.client.token_address.clone(),
+            module_address: self.account(module_addr),
+            module_name: module_name.to_string(),
+        };
+        let compliance_address = self.client.compliance_address.clone();
+        self.execute_contract(&compliance_address, &msg, vec![])
+    }
+
+    /// Adds the removal of a compliance module of the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `module_addr` - The address of the module contract
+    pub fn remove_compliance_module(mut self, module_addr: &Address) -> Self {
+        let msg = ExecuteMsg::RemoveComplianceModule {
+            token_address: self.client.token_address.clone(),
+            module_address: self.account(module_addr),
+        };
+        let compliance_address = self.client.compliance_address.clone();
+        self.execute_contract(&compliance_address, &msg, vec![])
+    }
+
+    /// Adds a status update of a compliance module of the token.
+    ///
+    /// # Arguments
+    ///
+    /// * `module_addr` - The address of the module contract
+    /// * `active` - Whether the module should be active
+    pub fn update_compliance_module(mut self, module_addr: &Address, active: bool) -> Self {
+        let msg = ExecuteMsg::UpdateComplianceModule {
+            token_address: self.client.token_address.clone(),
+            module_address: self.account(module_addr),
+            active,
+        };
+        let compliance_address = self.client.compliance_address.clone();
+        self.execute_contract(&compliance_address, &msg, vec![])
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[allow(clippy::enum_variant_names)]
 enum ExecuteMsg {
     AddComplianceModule {
         token_address: String,
@@ -1,8 +1,10 @@
-use cosmrs::{crypto::secp256k1::SigningKey, Gas};
+use std::sync::Arc;
+
+use crate::{Address, GasSetting, Signer};
 
 pub struct ComplianceModuleRequest {
-    pub from: String,
-    pub module_addr: String,
-    pub signer: SigningKey,
-    pub gas_limit: Gas,
+    pub from: Address,
+    pub module_addr: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
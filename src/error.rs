@@ -0,0 +1,298 @@
+//! Error types for the RWA SDK.
+//!
+//! Every fallible operation on [`RwaClient`](crate::RwaClient) returns a
+//! [`RwaError`], which lets callers tell an RPC outage apart from an invalid
+//! address, an encoding failure or a transaction rejected by the chain.
+
+use std::time::Duration;
+
+use cosmrs::proto::prost::{DecodeError, EncodeError};
+use cosmrs::rpc::endpoint::abci_query::AbciQuery;
+use thiserror::Error;
+
+/// ABCI codespace used by the Cosmos SDK for its own errors.
+const SDK_CODESPACE: &str = "sdk";
+
+/// `ErrOutOfGas` code in the `sdk` codespace.
+const OUT_OF_GAS_CODE: u32 = 11;
+
+/// `ErrWrongSequence` code in the `sdk` codespace.
+const WRONG_SEQUENCE_CODE: u32 = 32;
+
+/// Marker preceding the sequence expected by the chain in a mismatch log.
+const EXPECTED_SEQUENCE_MARKER: &str = "expected ";
+
+/// ABCI codespace used by the CosmWasm module.
+const WASM_CODESPACE: &str = "wasm";
+
+/// Suffix wasmd appends to the log of a failed contract execution.
+const WASM_EXECUTE_FAILED: &str = ": execute wasm contract failed";
+
+/// Marker preceding the contract error in the log of a failed message.
+const MESSAGE_INDEX_MARKER: &str = "message index: ";
+
+/// Fragments of the log of a contract query the contract does not support:
+/// the serde error of an unknown `QueryMsg` variant, or the error contracts
+/// return for queries they parse but do not implement.
+const UNSUPPORTED_QUERY_MARKERS: [&str; 2] = ["unknown variant", "unsupported query"];
+
+/// The error type returned by all RWA SDK operations.
+///
+/// The type is `Send + Sync`, so it can be propagated across tokio tasks.
+#[derive(Debug, Error)]
+pub enum RwaError {
+    /// The RPC endpoint could not be reached or returned a transport-level error.
+    #[error("transport error: {0}")]
+    Transport(#[from] cosmrs::rpc::Error),
+
+    /// The node answered a gRPC query with an error code.
+    #[error("query {path} failed (codespace `{codespace}`, code {code}): {log}")]
+    Query {
+        path: String,
+        code: u32,
+        codespace: String,
+        log: String,
+    },
+
+    /// The client configuration is invalid (RPC url, chain id, denom, ...).
+    #[error("invalid configuration: {0}")]
+    Config(String),
+
+    /// An address is not a valid bech32 or EVM hex address.
+    #[error("invalid address `{address}`: {reason}")]
+    InvalidAddress { address: String, reason: String },
+
+    /// A message or response could not be encoded or decoded.
+    #[error("encoding error: {0}")]
+    Encoding(String),
+
+    /// The account information of the transaction sender could not be resolved.
+    #[error("account lookup failed for `{address}`: {reason}")]
+    AccountLookup { address: String, reason: String },
+
+    /// The account does not exist on chain yet: it must receive tokens before
+    /// it can send transactions.
+    #[error("account `{0}` not found, it must receive tokens before sending transactions")]
+    AccountNotFound(String),
+
+    /// The transaction could not be signed.
+    #[error("signing error: {0}")]
+    Signing(String),
+
+    /// A key could not be read from, written to or decrypted from the keystore.
+    #[error("keystore error: {0}")]
+    Keystore(String),
+
+    /// The transaction failed during gas simulation and was not broadcast.
+    #[error("transaction simulation failed (codespace `{}`, code {}): {}", .0.codespace, .0.code, .0.log)]
+    Simulation(Box<TxFailure>),
+
+    /// The transaction was rejected by the node during `CheckTx`.
+    #[error("transaction {} rejected during CheckTx (codespace `{}`, code {}): {}", .0.tx_hash, .0.codespace, .0.code, .0.log)]
+    CheckTx(Box<TxFailure>),
+
+    /// The transaction was included in a block but its execution failed.
+    #[error("transaction {} failed during DeliverTx (codespace `{}`, code {}): {}", .0.tx_hash, .0.codespace, .0.code, .0.log)]
+    DeliverTx(Box<TxFailure>),
+
+    /// A broadcast transaction was not committed before the timeout elapsed.
+    ///
+    /// The transaction may still be included in a later block.
+    #[error("transaction {tx_hash} was not committed within {timeout:?}")]
+    TxTimeout { tx_hash: String, timeout: Duration },
+
+    /// The allowance of a spender is lower than the amount it tries to
+    /// transfer from the owner's tokens.
+    #[error(
+        "allowance of `{spender}` over the tokens of `{owner}` is {allowance}, {required} required"
+    )]
+    InsufficientAllowance {
+        owner: String,
+        spender: String,
+        allowance: u128,
+        required: u128,
+    },
+
+    /// The allowance of a spender over the owner's tokens has expired.
+    #[error("allowance of `{spender}` over the tokens of `{owner}` has expired")]
+    AllowanceExpired { owner: String, spender: String },
+
+    /// The compliance contract does not allow an account to receive the token.
+    #[error("`{0}` is not compliant for the token")]
+    NotCompliant(String),
+
+    /// The token is paused: no tokens can be transferred or minted until an
+    /// agent unpauses it.
+    #[error("token `{0}` is paused")]
+    TokenPaused(String),
+
+    /// A smart contract returned an error.
+    #[error("contract error: {0}")]
+    Contract(String),
+}
+
+impl RwaError {
+    /// Returns `true` if the transaction ran out of gas.
+    pub fn is_out_of_gas(&self) -> bool {
+        self.tx_failure().is_some_and(|failure| {
+            failure.code == OUT_OF_GAS_CODE && failure.codespace == SDK_CODESPACE
+        })
+    }
+
+    /// Returns `true` if the transaction was signed with a stale account sequence.
+    pub fn is_sequence_mismatch(&self) -> bool {
+        self.tx_failure().is_some_and(|failure| {
+            failure.code == WRONG_SEQUENCE_CODE && failure.codespace == SDK_CODESPACE
+        })
+    }
+
+    /// Returns `true` if a contract query failed because the contract does not support it.
+    pub(crate) fn is_unsupported_query(&self) -> bool {
+        match self {
+            RwaError::Contract(log) => {
+                let log = log.to_lowercase();
+                UNSUPPORTED_QUERY_MARKERS
+                    .iter()
+                    .any(|marker| log.contains(marker))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the details of a failed transaction, if this error was caused by one.
+    pub fn tx_failure(&self) -> Option<&TxFailure> {
+        match self {
+            RwaError::Simulation(failure)
+            | RwaError::CheckTx(failure)
+            | RwaError::DeliverTx(failure) => Some(failure),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn invalid_address(address: &str, reason: impl ToString) -> Self {
+        RwaError::InvalidAddress {
+            address: address.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub(crate) fn account_lookup(address: impl ToString, reason: impl ToString) -> Self {
+        RwaError::AccountLookup {
+            address: address.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Creates the error of a query on `path` the node answered with an error code.
+    pub(crate) fn query(path: &str, response: &AbciQuery) -> Self {
+        RwaError::Query {
+            path: path.to_string(),
+            code: response.code.value(),
+            codespace: response.codespace.clone(),
+            log: response.log.clone(),
+        }
+    }
+}
+
+/// Details of a transaction that was rejected by the node or failed on chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxFailure {
+    /// The transaction hash, empty for failed simulations
+    pub tx_hash: String,
+    /// Height of the block including the transaction, or `0` if it was never included
+    pub height: u64,
+    /// The non-zero ABCI result code
+    pub code: u32,
+    /// The module namespace of the result code
+    pub codespace: String,
+    /// The raw log returned by the node
+    pub log: String,
+    /// The error returned by the CosmWasm contract, if the failure originated there
+    pub contract_error: Option<String>,
+    /// Gas wanted/requested for the transaction
+    pub gas_wanted: i64,
+    /// Gas used by the transaction
+    pub gas_used: i64,
+}
+
+impl TxFailure {
+    /// Creates a failure record, extracting the contract error from the log.
+    pub(crate) fn new(
+        tx_hash: String,
+        height: u64,
+        code: u32,
+        codespace: String,
+        log: String,
+        gas_wanted: i64,
+        gas_used: i64,
+    ) -> Self {
+        let contract_error = parse_contract_error(&codespace, &log);
+        Self {
+            tx_hash,
+            height,
+            code,
+            codespace,
+            log,
+            contract_error,
+            gas_wanted,
+            gas_used,
+        }
+    }
+
+    /// Returns the account sequence the chain expected, for a sequence mismatch.
+    ///
+    /// The Cosmos SDK reports mismatches as
+    /// `account sequence mismatch, expected 5, got 3: incorrect account sequence`.
+    pub fn expected_sequence(&self) -> Option<u64> {
+        if self.code != WRONG_SEQUENCE_CODE || self.codespace != SDK_CODESPACE {
+            return None;
+        }
+
+        let start = self.log.find(EXPECTED_SEQUENCE_MARKER)? + EXPECTED_SEQUENCE_MARKER.len();
+        let digits = self.log[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(&self.log[start..], |end| &self.log[start..start + end]);
+        digits.parse().ok()
+    }
+}
+
+/// Extracts the contract error from the log of a failed wasm execution.
+///
+/// wasmd reports contract failures as
+/// `failed to execute message; message index: 0: <contract error>: execute wasm contract failed`.
+fn parse_contract_error(codespace: &str, log: &str) -> Option<String> {
+    if codespace != WASM_CODESPACE {
+        return None;
+    }
+
+    // Simulation logs carry a gas usage trailer after the wasm suffix
+    let end = log.find(WASM_EXECUTE_FAILED)?;
+    let message = &log[..end];
+    let message = match message.find(MESSAGE_INDEX_MARKER) {
+        Some(start) => {
+            let rest = &message[start + MESSAGE_INDEX_MARKER.len()..];
+            rest.split_once(": ").map_or(rest, |(_, error)| error)
+        }
+        None => message,
+    };
+
+    Some(message.to_string())
+}
+
+impl From<EncodeError> for RwaError {
+    fn from(err: EncodeError) -> Self {
+        RwaError::Encoding(err.to_string())
+    }
+}
+
+impl From<DecodeError> for RwaError {
+    fn from(err: DecodeError) -> Self {
+        RwaError::Encoding(err.to_string())
+    }
+}
+
+impl From<cosmwasm_std::StdError> for RwaError {
+    fn from(err: cosmwasm_std::StdError) -> Self {
+        RwaError::Encoding(err.to_string())
+    }
+}
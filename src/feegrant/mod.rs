@@ -0,0 +1,378 @@
+//! Fee allowances for the RWA SDK.
+//!
+//! Investors calling `add_identity` or `transfer` need native tokens to pay
+//! their fees. With the `feegrant` module the issuer grants them an allowance
+//! instead, and their transactions name the issuer as fee granter, either for
+//! every transaction of a client, see [`RwaClientBuilder::fee_granter`], or
+//! per batch, see [`TxBatch::fee_granter`].
+//!
+//! ```rust,no_run
+//! use std::sync::Arc;
+//!
+//! use cosmrs::Coin;
+//! use erc3643sdk::feegrant::request::GrantFeeAllowanceRequest;
+//! use erc3643sdk::feegrant::FeeAllowance;
+//! use erc3643sdk::{GasSetting, LocalSigner, RwaClient};
+//!
+//! # async fn run(client: RwaClient, issuer: Arc<LocalSigner>) -> Result<(), Box<dyn std::error::Error>> {
+//! client
+//!     .grant_fee_allowance(GrantFeeAllowanceRequest {
+//!         from: "kii1issuer...".parse()?,
+//!         grantee: "kii1investor...".parse()?,
+//!         allowance: FeeAllowance::Basic {
+//!             spend_limit: vec![Coin::new(1_000_000, "ukii")?],
+//!             expiration: None,
+//!         },
+//!         contract_calls_only: true,
+//!         signer: issuer,
+//!         gas_limit: GasSetting::default(),
+//!     })
+//!     .await?;
+//!
+//! // The investor's client
+//! let investor_client = RwaClient::builder()
+//!     .chain_id("kiichain")
+//!     .token_address("kii1token...")
+//!     .identity_address("kii1identity...")
+//!     .compliance_address("kii1compliance...")
+//!     .gas_prices("0.025ukii")
+//!     .fee_granter("kii1issuer...")
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`RwaClientBuilder::fee_granter`]: crate::RwaClientBuilder::fee_granter
+//! [`TxBatch::fee_granter`]: crate::TxBatch::fee_granter
+
+use std::time::{Duration, SystemTime};
+
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    AllowedMsgAllowance, BasicAllowance, MsgGrantAllowance, MsgRevokeAllowance, PeriodicAllowance,
+    QueryAllowanceRequest, QueryAllowanceResponse,
+};
+use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContract;
+use cosmrs::proto::prost::{Message, Name};
+use cosmrs::{Any, Coin};
+use request::{FeeAllowanceRequest, GrantFeeAllowanceRequest, RevokeFeeAllowanceRequest};
+
+use crate::{Address, ExecuteResponse, RwaClient, RwaError, TxBatch};
+
+pub mod request;
+
+/// Code of the Cosmos SDK `ErrNotFound` error, returned for a missing allowance.
+const NOT_FOUND_CODE: u32 = 38;
+
+/// The fees an account may spend from the allowance of its granter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeeAllowance {
+    /// A `BasicAllowance`: a total spend limit
+    Basic {
+        /// The total fees the grantee may spend, unlimited if empty
+        spend_limit: Vec<Coin>,
+        /// When the allowance expires, never if `None`
+        expiration: Option<SystemTime>,
+    },
+    /// A `PeriodicAllowance`: a spend limit renewed every period, within a total spend limit
+    Periodic {
+        /// The total fees the grantee may spend, unlimited if empty
+        spend_limit: Vec<Coin>,
+        /// When the allowance expires, never if `None`
+        expiration: Option<SystemTime>,
+        /// The duration after which the period spend limit is renewed
+        period: Duration,
+        /// The fees the grantee may spend in each period
+        period_spend_limit: Vec<Coin>,
+    },
+}
+
+/// A fee allowance stored on chain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeGrant {
+    /// The account paying the fees
+    pub granter: String,
+    /// The account whose fees are paid
+    pub grantee: String,
+    /// The fees the grantee may spend
+    pub allowance: FeeAllowance,
+    /// The type URLs of the messages whose fees are paid, any message if empty
+    pub allowed_messages: Vec<String>,
+}
+
+impl FeeAllowance {
+    /// Packs the allowance into the `Any` of a `MsgGrantAllowance`.
+    fn to_any(&self) -> Result<Any, RwaError> {
+        let allowance = match self {
+            FeeAllowance::Basic {
+                spend_limit,
+                expiration,
+            } => Any::from_msg(&basic_allowance(spend_limit, *expiration)),
+            FeeAllowance::Periodic {
+                spend_limit,
+                expiration,
+                period,
+                period_spend_limit,
+            } => {
+                let period_spend_limit: Vec<_> =
+                    period_spend_limit.iter().map(Into::into).collect();
+                Any::from_msg(&PeriodicAllowance {
+                    basic: Some(basic_allowance(spend_limit, *expiration)),
+                    period: Some((*period).try_into().map_err(|e| {
+                        RwaError::Config(format!("invalid allowance period: {}", e))
+                    })?),
+                    period_can_spend: period_spend_limit.clone(),
+                    period_spend_limit,
+                    // Set by the chain when the allowance is granted
+                    period_reset: None,
+                })
+            }
+        }?;
+        Ok(allowance)
+    }
+
+    /// Decodes a `BasicAllowance` or a `PeriodicAllowance`.
+    fn from_any(allowance: &Any) -> Result<Self, RwaError> {
+        if allowance.type_url == BasicAllowance::type_url() {
+            let basic: BasicAllowance = allowance.to_msg()?;
+            let (spend_limit, expiration) = from_basic_allowance(basic)?;
+            return Ok(FeeAllowance::Basic {
+                spend_limit,
+                expiration,
+            });
+        }
+
+        if allowance.type_url == PeriodicAllowance::type_url() {
+            let periodic: PeriodicAllowance = allowance.to_msg()?;
+            let (spend_limit, expiration) =
+                from_basic_allowance(periodic.basic.unwrap_or_default())?;
+            let period = periodic
+                .period
+                .unwrap_or_default()
+                .try_into()
+                .map_err(|e| RwaError::Encoding(format!("invalid allowance period: {}", e)))?;
+            return Ok(FeeAllowance::Periodic {
+                spend_limit,
+                expiration,
+                period,
+                period_spend_limit: coins(&periodic.period_spend_limit)?,
+            });
+        }
+
+        Err(RwaError::Encoding(format!(
+            "unsupported fee allowance type {}",
+            allowance.type_url
+        )))
+    }
+}
+
+impl RwaClient {
+    /// Grants an account an allowance paying its fees from the `from` account.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `GrantFeeAllowanceRequest` containing:
+    ///   - `from`: The granter, paying the fees
+    ///   - `grantee`: The account whose fees are paid
+    ///   - `allowance`: The fees the grantee may spend
+    ///   - `contract_calls_only`: Whether only the fees of contract calls,
+    ///     such as RWA operations, are paid. The `feegrant` module restricts
+    ///     message types, not the contracts called.
+    ///   - `signer`: The signing key of the granter
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails, e.g. because the grantee already
+    /// has an allowance of the granter.
+    pub async fn grant_fee_allowance(
+        &self,
+        request: GrantFeeAllowanceRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = grant_allowance_msg(
+            self.account_id(&request.from)?.as_ref(),
+            self.account_id(&request.grantee)?.as_ref(),
+            &request.allowance,
+            request.contract_calls_only,
+        )?;
+        self.send(
+            &request.from,
+            vec![msg],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Revokes the fee allowance granted to an account by the `from` account.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `RevokeFeeAllowanceRequest` containing:
+    ///   - `from`: The granter
+    ///   - `grantee`: The account whose fees were paid
+    ///   - `signer`: The signing key of the granter
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn revoke_fee_allowance(
+        &self,
+        request: RevokeFeeAllowanceRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = revoke_allowance_msg(
+            self.account_id(&request.from)?.as_ref(),
+            self.account_id(&request.grantee)?.as_ref(),
+        )?;
+        self.send(
+            &request.from,
+            vec![msg],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Retrieves the fee allowance granted to an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `FeeAllowanceRequest` containing the granter and the grantee
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the FeeGrant, `None` if the grantee has no
+    /// allowance of the granter, or an error
+    pub async fn fee_allowance(
+        &self,
+        request: FeeAllowanceRequest,
+    ) -> Result<Option<FeeGrant>, RwaError> {
+        let query = QueryAllowanceRequest {
+            granter: self.account_id(&request.granter)?.to_string(),
+            grantee: self.account_id(&request.grantee)?.to_string(),
+        };
+        let path = "/cosmos.feegrant.v1beta1.Query/Allowance";
+
+        let response = self
+            .transport
+            .abci_query(path, query.encode_to_vec())
+            .await?;
+
+        if response.code.is_err() {
+            if response.code.value() == NOT_FOUND_CODE && response.codespace == "sdk" {
+                return Ok(None);
+            }
+            return Err(RwaError::query(path, &response));
+        }
+
+        let Some(grant) = QueryAllowanceResponse::decode(response.value.as_slice())?.allowance
+        else {
+            return Ok(None);
+        };
+        let allowance = grant
+            .allowance
+            .ok_or_else(|| RwaError::Encoding("fee grant has no allowance".to_string()))?;
+
+        let (allowance, allowed_messages) = if allowance.type_url == AllowedMsgAllowance::type_url()
+        {
+            let allowed: AllowedMsgAllowance = allowance.to_msg()?;
+            let inner = allowed
+                .allowance
+                .ok_or_else(|| RwaError::Encoding("fee grant has no allowance".to_string()))?;
+            (FeeAllowance::from_any(&inner)?, allowed.allowed_messages)
+        } else {
+            (FeeAllowance::from_any(&allowance)?, Vec::new())
+        };
+
+        Ok(Some(FeeGrant {
+            granter: grant.granter,
+            grantee: grant.grantee,
+            allowance,
+            allowed_messages,
+        }))
+    }
+}
+
+impl TxBatch<'_> {
+    /// Adds a grant of a fee allowance paid by the batch sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `grantee` - The account whose fees are paid
+    /// * `allowance` - The fees the grantee may spend
+    /// * `contract_calls_only` - Whether only the fees of contract calls are paid
+    pub fn grant_fee_allowance(
+        mut self,
+        grantee: &Address,
+        allowance: &FeeAllowance,
+        contract_calls_only: bool,
+    ) -> Self {
+        let grantee = self.account(grantee);
+        let msg = grant_allowance_msg(self.from(), &grantee, allowance, contract_calls_only);
+        self.push_message(msg)
+    }
+
+    /// Adds the revocation of a fee allowance paid by the batch sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `grantee` - The account whose fees were paid
+    pub fn revoke_fee_allowance(mut self, grantee: &Address) -> Self {
+        let grantee = self.account(grantee);
+        let msg = revoke_allowance_msg(self.from(), &grantee);
+        self.push_message(msg)
+    }
+}
+
+fn grant_allowance_msg(
+    granter: &str,
+    grantee: &str,
+    allowance: &FeeAllowance,
+    contract_calls_only: bool,
+) -> Result<Any, RwaError> {
+    let mut allowance = allowance.to_any()?;
+    if contract_calls_only {
+        allowance = Any::from_msg(&AllowedMsgAllowance {
+            allowance: Some(allowance),
+            allowed_messages: vec![MsgExecuteContract::type_url()],
+        })?;
+    }
+
+    Ok(Any::from_msg(&MsgGrantAllowance {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+        allowance: Some(allowance),
+    })?)
+}
+
+fn revoke_allowance_msg(granter: &str, grantee: &str) -> Result<Any, RwaError> {
+    Ok(Any::from_msg(&MsgRevokeAllowance {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+    })?)
+}
+
+fn basic_allowance(spend_limit: &[Coin], expiration: Option<SystemTime>) -> BasicAllowance {
+    BasicAllowance {
+        spend_limit: spend_limit.iter().map(Into::into).collect(),
+        expiration: expiration.map(Into::into),
+    }
+}
+
+fn from_basic_allowance(
+    basic: BasicAllowance,
+) -> Result<(Vec<Coin>, Option<SystemTime>), RwaError> {
+    let expiration = basic
+        .expiration
+        .map(SystemTime::try_from)
+        .transpose()
+        .map_err(|e| RwaError::Encoding(format!("invalid allowance expiration: {}", e)))?;
+    Ok((coins(&basic.spend_limit)?, expiration))
+}
+
+fn coins(coins: &[cosmrs::proto::cosmos::base::v1beta1::Coin]) -> Result<Vec<Coin>, RwaError> {
+    coins
+        .iter()
+        .map(|coin| Coin::try_from(coin).map_err(|e| RwaError::Encoding(e.to_string())))
+        .collect()
+}
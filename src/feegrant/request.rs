@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use super::FeeAllowance;
+use crate::{Address, GasSetting, Signer};
+
+/// Request structure for granting an account a fee allowance
+pub struct GrantFeeAllowanceRequest {
+    pub from: Address,
+    pub grantee: Address,
+    pub allowance: FeeAllowance,
+    pub contract_calls_only: bool,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for revoking the fee allowance of an account
+pub struct RevokeFeeAllowanceRequest {
+    pub from: Address,
+    pub grantee: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for retrieving the fee allowance of an account
+pub struct FeeAllowanceRequest {
+    pub granter: Address,
+    pub grantee: Address,
+}
@@ -0,0 +1,170 @@
+//! Gas limit and fee configuration for the RWA SDK.
+//!
+//! Transactions either use a fixed gas limit or are simulated against the
+//! chain first, in which case the simulated gas usage is scaled by a
+//! multiplier and clamped to the bounds of the client's [`GasConfig`].
+//! The fee is then priced from a decimal [`GasPrice`] such as `0.025ukii`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use cosmrs::{Coin, Denom, Gas};
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::RwaError;
+
+/// Default multiplier applied to the simulated gas usage.
+pub const DEFAULT_GAS_MULTIPLIER: f64 = 1.3;
+
+/// Default lower bound for estimated gas limits.
+pub const DEFAULT_GAS_FLOOR: Gas = 100_000;
+
+/// Default upper bound for estimated gas limits.
+pub const DEFAULT_GAS_CEILING: Gas = 10_000_000;
+
+/// How the gas limit of a transaction is determined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GasSetting {
+    /// Simulate the transaction and multiply the simulated gas usage by `multiplier`.
+    Auto { multiplier: f64 },
+    /// Use the given gas limit as is.
+    Fixed(Gas),
+}
+
+impl Default for GasSetting {
+    fn default() -> Self {
+        GasSetting::Auto {
+            multiplier: DEFAULT_GAS_MULTIPLIER,
+        }
+    }
+}
+
+impl From<Gas> for GasSetting {
+    fn from(gas_limit: Gas) -> Self {
+        GasSetting::Fixed(gas_limit)
+    }
+}
+
+/// Bounds applied to gas limits estimated through simulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasConfig {
+    /// The minimum gas limit of an estimated transaction
+    pub floor: Gas,
+    /// The maximum gas limit of an estimated transaction
+    pub ceiling: Gas,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        Self {
+            floor: DEFAULT_GAS_FLOOR,
+            ceiling: DEFAULT_GAS_CEILING,
+        }
+    }
+}
+
+impl GasConfig {
+    /// Computes the gas limit for a simulated gas usage.
+    ///
+    /// # Arguments
+    ///
+    /// * `gas_used` - The gas used by the simulated transaction
+    /// * `multiplier` - The safety multiplier applied to `gas_used`
+    ///
+    /// # Returns
+    ///
+    /// The scaled gas usage, clamped between `floor` and `ceiling`
+    pub fn estimate(&self, gas_used: Gas, multiplier: f64) -> Gas {
+        let estimate = (gas_used as f64 * multiplier).ceil() as Gas;
+        estimate.max(self.floor).min(self.ceiling)
+    }
+
+    /// Checks that the floor does not exceed the ceiling.
+    pub(crate) fn validate(&self) -> Result<(), RwaError> {
+        if self.floor > self.ceiling {
+            return Err(RwaError::Config(format!(
+                "gas floor {} exceeds gas ceiling {}",
+                self.floor, self.ceiling
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The price paid per unit of gas, e.g. `0.025ukii`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasPrice {
+    /// The amount paid per unit of gas
+    pub amount: Decimal,
+    /// The unit of token the fee is paid in
+    pub denom: String,
+}
+
+impl GasPrice {
+    /// Creates a gas price, validating the denomination.
+    pub fn new(amount: Decimal, denom: &str) -> Result<Self, RwaError> {
+        Denom::from_str(denom)
+            .map_err(|e| RwaError::Config(format!("invalid denom `{}`: {}", denom, e)))?;
+        Ok(Self {
+            amount,
+            denom: denom.to_string(),
+        })
+    }
+
+    /// Parses a comma separated list of gas prices, e.g. `0.025ukii,0.1uusdc`.
+    pub fn parse_list(gas_prices: &str) -> Result<Vec<Self>, RwaError> {
+        gas_prices
+            .split(',')
+            .map(str::trim)
+            .filter(|gas_price| !gas_price.is_empty())
+            .map(Self::from_str)
+            .collect()
+    }
+
+    /// Computes the fee for a gas limit, rounding up to the next whole unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `gas_limit` - The gas limit of the transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the fee as a Coin or an error if it overflows
+    pub fn fee(&self, gas_limit: Gas) -> Result<Coin, RwaError> {
+        let amount = Uint128::from(gas_limit)
+            .checked_mul_ceil(self.amount)
+            .map_err(|e| {
+                RwaError::Config(format!("fee for {} gas at {}: {}", gas_limit, self, e))
+            })?;
+        let denom = self
+            .denom
+            .parse()
+            .map_err(|e| RwaError::Config(format!("invalid denom `{}`: {}", self.denom, e)))?;
+        Ok(Coin {
+            amount: amount.u128(),
+            denom,
+        })
+    }
+}
+
+impl FromStr for GasPrice {
+    type Err = RwaError;
+
+    fn from_str(gas_price: &str) -> Result<Self, Self::Err> {
+        let invalid = || RwaError::Config(format!("invalid gas price `{}`", gas_price));
+
+        let denom_start = gas_price
+            .find(|c: char| c.is_ascii_alphabetic())
+            .ok_or_else(invalid)?;
+        let (amount, denom) = gas_price.split_at(denom_start);
+        let amount = Decimal::from_str(amount).map_err(|_| invalid())?;
+
+        Self::new(amount, denom)
+    }
+}
+
+impl fmt::Display for GasPrice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
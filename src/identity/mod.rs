@@ -10,7 +10,7 @@ use request::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{ExecuteResponse, RwaClient};
+use crate::{Address, ExecuteResponse, RwaClient, RwaError, TxBatch};
 
 pub mod request;
 
@@ -33,7 +33,7 @@ impl RwaClient {
     pub async fn add_identity(
         &self,
         request: AddIdentityRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::AddIdentity {
             country: request.country,
         };
@@ -42,7 +42,7 @@ impl RwaClient {
             &msg,
             self.identity_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
@@ -67,17 +67,17 @@ impl RwaClient {
     pub async fn update_identity(
         &self,
         request: UpdateIdentityRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::UpdateCountry {
             new_country: request.new_country,
-            identity_owner: request.identity_owner,
+            identity_owner: self.account_id(&request.identity_owner)?.to_string(),
         };
         self.execute(
             &request.from,
             &msg,
             self.identity_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
@@ -101,16 +101,16 @@ impl RwaClient {
     pub async fn remove_identity(
         &self,
         request: RemoveIdentityRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::RemoveIdentity {
-            identity_owner: request.identity_owner,
+            identity_owner: self.account_id(&request.identity_owner)?.to_string(),
         };
         self.execute(
             &request.from,
             &msg,
             self.identity_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
@@ -132,20 +132,17 @@ impl RwaClient {
     ///
     /// A `ExecuteResponse` containing information about the transaction if successful,
     /// or an error if the operation fails.
-    pub async fn add_claim(
-        &self,
-        request: AddClaimRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    pub async fn add_claim(&self, request: AddClaimRequest) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::AddClaim {
             claim: request.claim,
-            identity_owner: request.identity_owner,
+            identity_owner: self.account_id(&request.identity_owner)?.to_string(),
         };
         self.execute(
             &request.from,
             &msg,
             self.identity_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
@@ -170,17 +167,17 @@ impl RwaClient {
     pub async fn remove_claim(
         &self,
         request: RemoveClaimRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
         let msg = ExecuteMsg::RemoveClaim {
             claim_topic: request.claim_topic,
-            identity_owner: request.identity_owner,
+            identity_owner: self.account_id(&request.identity_owner)?.to_string(),
         };
         self.execute(
             &request.from,
             &msg,
             self.identity_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
@@ -202,9 +199,9 @@ impl RwaClient {
     pub async fn get_validated_claims(
         &self,
         request: GetValidatedClaimsRequest,
-    ) -> Result<Vec<Claim>, Box<dyn std::error::Error>> {
+    ) -> Result<Vec<Claim>, RwaError> {
         let msg = QueryMsg::GetValidatedClaimsForUser {
-            identity_owner: request.identity_owner,
+            identity_owner: self.account_id(&request.identity_owner)?.to_string(),
         };
         self.query(&self.identity_address, &msg).await
     }
@@ -227,17 +224,115 @@ impl RwaClient {
     pub async fn check_token_compliance(
         &self,
         request: CheckUserForTokenComplianceRequest,
-    ) -> Result<bool, Box<dyn std::error::Error>> {
+    ) -> Result<bool, RwaError> {
+        self.token_compliance(
+            self.account_id(&request.token_address)?.to_string(),
+            Some(self.account_id(&request.from)?.to_string()),
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Checks with the compliance contract whether a token operation is allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token contract
+    /// * `from` - The account sending the tokens, if any
+    /// * `to` - The account receiving the tokens, if any
+    /// * `amount` - The amount of tokens, if known
+    pub(crate) async fn token_compliance(
+        &self,
+        token_address: String,
+        from: Option<String>,
+        to: Option<String>,
+        amount: Option<Uint128>,
+    ) -> Result<bool, RwaError> {
         let msg = QueryMsg::CheckTokenCompliance {
-            token_address: request.token_address,
-            from: Some(request.from),
-            to: None,
-            amount: None,
+            token_address,
+            from,
+            to,
+            amount,
         };
         self.query(&self.compliance_address, &msg).await
     }
 }
 
+impl TxBatch<'_> {
+    /// Adds the registration of the batch sender's identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The country associated with the identity
+    pub fn add_identity(self, country: &str) -> Self {
+        let msg = ExecuteMsg::AddIdentity {
+            country: country.to_string(),
+        };
+        let identity_address = self.client.identity_address.clone();
+        self.execute_contract(&identity_address, &msg, vec![])
+    }
+
+    /// Adds an update of the country associated with an identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_country` - The new country to associate with the identity
+    /// * `identity_owner` - The owner of the identity to update
+    pub fn update_identity(mut self, new_country: &str, identity_owner: &Address) -> Self {
+        let msg = ExecuteMsg::UpdateCountry {
+            new_country: new_country.to_string(),
+            identity_owner: self.account(identity_owner),
+        };
+        let identity_address = self.client.identity_address.clone();
+        self.execute_contract(&identity_address, &msg, vec![])
+    }
+
+    /// Adds the removal of an identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity_owner` - The owner of the identity to remove
+    pub fn remove_identity(mut self, identity_owner: &Address) -> Self {
+        let msg = ExecuteMsg::RemoveIdentity {
+            identity_owner: self.account(identity_owner),
+        };
+        let identity_address = self.client.identity_address.clone();
+        self.execute_contract(&identity_address, &msg, vec![])
+    }
+
+    /// Adds a claim to an identity, the batch sender being a trusted issuer.
+    ///
+    /// # Arguments
+    ///
+    /// * `claim` - The claim to add
+    /// * `identity_owner` - The owner of the identity to add the claim to
+    pub fn add_claim(mut self, claim: Claim, identity_owner: &Address) -> Self {
+        let msg = ExecuteMsg::AddClaim {
+            claim,
+            identity_owner: self.account(identity_owner),
+        };
+        let identity_address = self.client.identity_address.clone();
+        self.execute_contract(&identity_address, &msg, vec![])
+    }
+
+    /// Adds the removal of a claim from an identity, the batch sender being a
+    /// trusted issuer.
+    ///
+    /// # Arguments
+    ///
+    /// * `claim_topic` - The topic of the claim to remove
+    /// * `identity_owner` - The owner of the identity to remove the claim from
+    pub fn remove_claim(mut self, claim_topic: Uint128, identity_owner: &Address) -> Self {
+        let msg = ExecuteMsg::RemoveClaim {
+            claim_topic,
+            identity_owner: self.account(identity_owner),
+        };
+        let identity_address = self.client.identity_address.clone();
+        self.execute_contract(&identity_address, &msg, vec![])
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Claim {
     pub topic: Uint128,
@@ -1,57 +1,59 @@
-use cosmrs::{crypto::secp256k1::SigningKey, Gas};
+use std::sync::Arc;
+
 use cosmwasm_std::Uint128;
 
 use super::Claim;
+use crate::{Address, GasSetting, Signer};
 
 /// Request structure for adding an identity
 pub struct AddIdentityRequest {
-    pub from: String,
+    pub from: Address,
     pub country: String,
-    pub signer: SigningKey,
-    pub gas_limit: Gas,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
 /// Request structure for updating an identity
 pub struct UpdateIdentityRequest {
-    pub from: String,
+    pub from: Address,
     pub new_country: String,
-    pub identity_owner: String,
-    pub signer: SigningKey,
-    pub gas_limit: Gas,
+    pub identity_owner: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
 
 /// Request structure for removing an identity
 pub struct RemoveIdentityRequest {
-    pub from: String,
-    pub identity_owner: String,
-    pub signer: SigningKey,
-    pub gas_limit: Gas,
+    pub from: Address,
+    pub identity_owner: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
 
 /// Request structure for adding a claim to user
 pub struct AddClaimRequest {
-    pub from: String,
+    pub from: Address,
     pub claim: Claim,
-    pub identity_owner: String,
-    pub signer: SigningKey,
-    pub gas_limit: Gas,
+    pub identity_owner: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
 
 /// Request structure for removing a claim
 pub struct RemoveClaimRequest {
-    pub from: String,
+    pub from: Address,
     pub claim_topic: Uint128,
-    pub identity_owner: String,
-    pub signer: SigningKey,
-    pub gas_limit: Gas,
+    pub identity_owner: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
 
 /// Request structure for retrieving validated claims for user
 pub struct GetValidatedClaimsRequest {
-    pub identity_owner: String,
+    pub identity_owner: Address,
 }
 
 /// Request structure for to check if user is compliant for token
 pub struct CheckUserForTokenComplianceRequest {
-    pub token_address: String,
-    pub from: String,
+    pub token_address: Address,
+    pub from: Address,
 }
@@ -6,45 +6,70 @@
 //!
 //! ## Features
 //!
-//! - Token transfers and balance checks
+//! - Client configuration through a builder, TOML/JSON network profiles or
+//!   `RWA_*` environment variables
+//! - Token transfers and balance checks, allowances, minting and burning
+//! - Agent freezes of whole addresses or part of their tokens
+//! - Token pause and unpause, with transfers and mints failing fast while paused
 //! - Identity registration and management
 //! - Compliance module integration
-//! - Blockchain interaction via RPC
+//! - Gas estimation through transaction simulation, with fees priced from
+//!   decimal gas prices such as `0.025ukii`
+//! - Commit, sync or async broadcasting, with confirmation polling through
+//!   `wait_for_tx`
+//! - Concurrent transactions from one account, signed with consecutive sequences
+//!   from a local cache that resyncs on sequence mismatches
+//! - Atomic multi-message transactions through `TxBatch`, with per-message results
+//! - Offline signing: build an `UnsignedTx`, sign it on an air-gapped machine and
+//!   broadcast the signed bytes later
+//! - k-of-n multisig accounts, with partial signatures collected in-process or as files
+//! - Authz delegation: grant agents contract calls of the issuer and send their
+//!   operations wrapped in `MsgExec`
+//! - Fee grants: issue, query and revoke `feegrant` allowances, and send transactions
+//!   with a fee granter or a co-signing fee payer
+//! - Addresses in bech32 or `0x` EVM form, converted with the chain's prefix and
+//!   validated against their bech32 or EIP-55 checksum
+//! - Signing through a pluggable `Signer`, with in-memory keys or a remote signing
+//!   service (HSM/KMS), in direct or legacy Amino JSON sign mode
+//! - BIP39 mnemonic wallets with BIP44 derivation of indexed accounts
+//! - Password-encrypted on-disk keystore for issuer and agent keys
+//! - Blockchain interaction via RPC, behind a pluggable `Transport` with an
+//!   in-memory mock for unit tests
 //!
 //! ## Usage Example
 //!
-//! ```rust
-//! use rwa_sdk::RwaClient;
-//! use cosmrs::crypto::secp256k1::SigningKey;
+//! ```rust,no_run
+//! use std::sync::Arc;
+//!
+//! use erc3643sdk::token::request::{TokenInfoRequest, TransferMessageRequest};
+//! use erc3643sdk::{GasSetting, LocalSigner, RwaClient};
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     // Initialize the client
-//!     let client = RwaClient::new(
-//!         "http://rpc.example.com:26657",
-//!         "my-chain-id",
-//!         "cosmos1token...",
-//!         "cosmos1identity...",
-//!         "cosmos1compliance...",
-//!         "sei",
-//!         "gas_price"
-//!
-//!     )?;
+//!     let client = RwaClient::builder()
+//!         .rpc_url("http://rpc.example.com:26657")
+//!         .chain_id("my-chain-id")
+//!         .token_address("cosmos1token...")
+//!         .identity_address("cosmos1identity...")
+//!         .compliance_address("cosmos1compliance...")
+//!         .gas_prices("0.1usei")
+//!         .build()?;
 //!
 //!     // Perform a token transfer
-//!     let signer = SigningKey::from_slice(&[/* your private key */])?;
+//!     let signer = Arc::new(LocalSigner::from_slice(&[/* your private key */])?);
 //!     let transfer_result = client.transfer(TransferMessageRequest {
-//!         from: "cosmos1sender...".to_string(),
-//!         to: "cosmos1recipient...".to_string(),
+//!         from: "cosmos1sender...".parse()?,
+//!         to: "cosmos1recipient...".parse()?,
 //!         amount: 100,
 //!         signer,
-//!         gas_limit
+//!         gas_limit: GasSetting::default(),
 //!     }).await?;
-//!     println!("Transfer hash: {}", transfer_result.hash);
+//!     println!("Transfer hash: {}", transfer_result.tx_hash);
 //!
 //!     // Check a balance
 //!     let balance = client.balance(TokenInfoRequest {
-//!         address: "cosmos1address...".to_string(),
+//!         address: "cosmos1address...".parse()?,
 //!     }).await?;
 //!     println!("Balance: {}", balance.balance);
 //!
@@ -56,45 +81,134 @@
 //! transfer, and check an account balance. Error handling and proper setup of the
 //! signing key are crucial for production use.
 //!
+//! ## Errors
+//!
+//! All operations return a [`RwaError`], which distinguishes transport failures,
+//! invalid addresses, encoding problems, signing failures and transactions
+//! rejected by the chain or by a contract.
+//!
 //! For more detailed information on each function and module, please refer to their
 //! respective documentation.
 
-use cosmrs::proto::cosmos::auth::v1beta1::BaseAccount;
-use cosmrs::proto::cosmos::base::tendermint::v1beta1::AbciQueryResponse;
+use cosmrs::proto::cosmos::auth::v1beta1::{
+    BaseAccount, QueryAccountInfoRequest, QueryAccountInfoResponse, QueryAccountRequest,
+    QueryAccountResponse,
+};
+use cosmrs::proto::cosmos::base::node::v1beta1::{ConfigRequest, ConfigResponse};
+use cosmrs::proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse, TxRaw};
+use cosmrs::proto::cosmwasm::wasm::v1::QuerySmartContractStateResponse;
 use cosmrs::proto::prost::Message;
-use cosmrs::rpc::HttpClient;
-use cosmrs::tendermint::abci::Event;
+use cosmrs::rpc::endpoint::abci_query::AbciQuery;
+use cosmrs::rpc::error::ErrorDetail;
+use cosmrs::tendermint::abci::{types::ExecTxResult, Event};
+use cosmrs::tendermint::Hash;
 use cosmrs::{
     proto::cosmwasm::wasm::v1::MsgExecuteContract,
-    rpc::Client,
     tendermint::chain::Id,
-    tx::{self, Fee, MessageExt, SignDoc, SignerInfo},
+    tx::{self, AuthInfo, Body, Fee, MessageExt},
     AccountId, Coin,
 };
 use cosmrs::{Any, Gas};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
 
+pub mod account;
+pub mod address;
+mod amino;
+pub mod authz;
+pub mod batch;
+pub mod broadcast;
+pub mod builder;
 pub mod compliance;
+pub mod error;
+pub mod feegrant;
+pub mod gas;
 pub mod identity;
+pub mod offline;
+mod sequence;
+pub mod signer;
 pub mod token;
+pub mod transport;
+
+pub use account::AccountTypes;
+pub use address::Address;
+pub use authz::{Authorization, ContractGrant, ContractLimit};
+pub use batch::{BatchResponse, MessageResult, TxBatch};
+pub use broadcast::BroadcastMode;
+pub use builder::{NetworkConfig, RwaClientBuilder};
+pub use error::{RwaError, TxFailure};
+pub use feegrant::{FeeAllowance, FeeGrant};
+pub use gas::{GasConfig, GasPrice, GasSetting};
+pub use offline::UnsignedTx;
+pub use signer::{
+    HdPath, KeyInfo, KeyType, Keystore, LocalSigner, MultisigAccount, PartialSignature,
+    RemoteSigner, SignMode, Signer, Wallet,
+};
+pub use token::TokenBalance;
+#[cfg(feature = "mock")]
+pub use transport::MockTransport;
+pub use transport::Transport;
+
+use sequence::SequenceCache;
+use signer::{TxKey, TxSigners};
+
+/// Number of times a transaction rejected for an account sequence mismatch is retried.
+const MAX_SEQUENCE_RETRIES: usize = 2;
 
 #[derive(Debug, Clone)]
 pub struct RwaClient {
-    rpc_client: HttpClient,
-    chain_id: String,
+    transport: Arc<dyn Transport>,
+    chain_id: Id,
     token_address: String,
     identity_address: String,
     compliance_address: String,
-    denom: String,
-    gas_price: Gas,
+    gas_prices: Vec<GasPrice>,
+    query_min_gas_price: bool,
+    gas_config: GasConfig,
+    broadcast_mode: BroadcastMode,
+    tx_poll_interval: Duration,
+    sequences: Arc<SequenceCache>,
+    authz_exec: bool,
+    fee_granter: Option<AccountId>,
+    account_prefix: String,
+    account_types: Arc<AccountTypes>,
 }
 
+#[derive(Debug, Clone, Copy)]
 struct AccountInfoResponse {
     pub account_number: u64,
     pub sequence: u64,
 }
 
+/// Who pays the fee of a transaction instead of its signer.
+#[derive(Default)]
+pub(crate) struct FeeOptions<'a> {
+    /// The account whose fee allowance pays the fee, the client's fee granter if `None`
+    pub(crate) granter: Option<AccountId>,
+    /// The signer of the account paying the fee, co-signing the transaction
+    pub(crate) payer: Option<&'a dyn Signer>,
+}
+
+/// The fee payer of a transaction, with the state of its account.
+struct FeePayer<'a> {
+    signer: &'a dyn Signer,
+    account_id: AccountId,
+    account_info: AccountInfoResponse,
+}
+
+impl FeePayer<'_> {
+    fn key(&self) -> TxKey {
+        TxKey::Single {
+            public_key: self.signer.public_key(),
+            key_type: self.signer.key_type(),
+            sign_mode: self.signer.sign_mode(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecuteResponse {
     /// The transaction hash
@@ -107,13 +221,41 @@ pub struct ExecuteResponse {
     pub gas_wanted: i64,
     /// Events emitted during execution
     pub events: Vec<Event>,
-    /// Height of the block where this transaction was committed
+    /// Height of the block where this transaction was committed, 0 if it was
+    /// broadcast without waiting for its inclusion
     pub height: u64,
 }
 
+impl ExecuteResponse {
+    /// Builds the response of a transaction that is not committed yet.
+    fn pending(tx_hash: String) -> Self {
+        Self {
+            tx_hash,
+            data: Vec::new(),
+            gas_used: 0,
+            gas_wanted: 0,
+            events: Vec::new(),
+            height: 0,
+        }
+    }
+
+    /// Returns `true` if the transaction is known to be committed in a block.
+    pub fn is_committed(&self) -> bool {
+        self.height > 0
+    }
+}
+
 impl RwaClient {
+    /// Returns a builder for configuring a new RwaClient.
+    pub fn builder() -> RwaClientBuilder {
+        RwaClientBuilder::new()
+    }
+
     /// Creates a new RwaClient instance.
     ///
+    /// This is a shorthand for [`RwaClient::builder`] with every setting given
+    /// positionally; the builder is usually easier to read.
+    ///
     /// # Arguments
     ///
     /// * `rpc_url` - The URL of the RPC endpoint
@@ -122,10 +264,10 @@ impl RwaClient {
     /// * `identity_address` - The address of the identity contract
     /// * `compliance_address` - The address of the compliance contract
     /// * `denom` - The unit of token
-    /// * `gas_price` - The amount willing to pay for each unit of gas
+    /// * `gas_price` - The whole amount of `denom` willing to pay for each unit of gas
     /// # Returns
     ///
-    /// A Result containing the RwaClient instance or an error
+    /// A Result containing the RwaClient instance or an error if a setting is invalid
     pub fn new(
         rpc_url: &str,
         chain_id: &str,
@@ -134,18 +276,56 @@ impl RwaClient {
         compliance_address: &str,
         denom: &str,
         gas_price: Gas,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
-        let rpc_client = HttpClient::new(rpc_url)?;
-
-        Ok(Self {
-            rpc_client,
-            chain_id: chain_id.to_string(),
-            token_address: token_address.to_string(),
-            identity_address: identity_address.to_string(),
-            compliance_address: compliance_address.to_string(),
-            denom: denom.to_string(),
-            gas_price,
-        })
+    ) -> Result<Self, RwaError> {
+        Self::builder()
+            .rpc_url(rpc_url)
+            .chain_id(chain_id)
+            .token_address(token_address)
+            .identity_address(identity_address)
+            .compliance_address(compliance_address)
+            .gas_prices(&format!("{}{}", gas_price, denom))
+            .build()
+    }
+
+    /// Replaces the transport used to reach the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport used for queries and broadcasts, e.g. a `MockTransport`
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Sets the bounds applied to automatically estimated gas limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `gas_config` - The floor and ceiling for simulated gas limits
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the RwaClient or `RwaError::Config` if the floor
+    /// exceeds the ceiling
+    pub fn with_gas_config(mut self, gas_config: GasConfig) -> Result<Self, RwaError> {
+        gas_config.validate()?;
+        self.gas_config = gas_config;
+        Ok(self)
+    }
+
+    /// Returns the bech32 prefix of account addresses on the client's chain.
+    pub fn account_prefix(&self) -> &str {
+        &self.account_prefix
+    }
+
+    /// Returns the account ID of an address on the client's chain.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the AccountId, or `RwaError::InvalidAddress` for a
+    /// bech32 address of another chain
+    pub(crate) fn account_id(&self, address: &Address) -> Result<AccountId, RwaError> {
+        address.on_chain(&self.account_prefix)
     }
 
     /// Executes a contract call that modifies the state.
@@ -156,81 +336,587 @@ impl RwaClient {
     /// * `msg` - The message to be executed
     /// * `contract_address` - The address of the contract to execute
     /// * `funds` - Any funds to be sent with the transaction
-    /// * `signer` - The signing key for the transaction
+    /// * `signer` - The signer of the transaction
+    /// * `gas` - A fixed gas limit, or automatic estimation through simulation
     ///
     /// # Returns
     ///
-    /// A Result containing the transaction hash as a String or an error
+    /// A Result containing the ExecuteResponse, or an error. A transaction rejected
+    /// during `CheckTx` or failing during `DeliverTx` returns `RwaError::CheckTx` or
+    /// `RwaError::DeliverTx` with the result code, log and gas usage. Outside of
+    /// `BroadcastMode::Commit` the response only carries the transaction hash,
+    /// see [`RwaClient::wait_for_tx`].
     async fn execute<T: serde::Serialize>(
         &self,
-        from: &str,
+        from: &Address,
         msg: &T,
         contract_address: String,
         funds: Vec<Coin>,
-        signer: &cosmrs::crypto::secp256k1::SigningKey,
-        gas_limit: Gas,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
-        let execute_msg = MsgExecuteContract {
-            sender: from.to_string(),
-            contract: contract_address,
-            msg: cosmwasm_std::to_json_binary(msg)?.into(),
-            funds: funds.into_iter().map(|c| c.into()).collect(),
+        signer: &dyn Signer,
+        gas: GasSetting,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let sender = self.account_id(from)?;
+        let any_msg = execute_contract_msg(sender.as_ref(), &contract_address, msg, funds)?;
+        self.send(from, vec![any_msg], signer, gas).await
+    }
+
+    /// Signs messages sent by `from` into a transaction and broadcasts it.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The address sending the messages
+    /// * `msgs` - The messages of the transaction
+    /// * `signer` - The signer of the transaction
+    /// * `gas` - A fixed gas limit, or automatic estimation through simulation
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ExecuteResponse or an error
+    async fn send(
+        &self,
+        from: &Address,
+        msgs: Vec<Any>,
+        signer: &dyn Signer,
+        gas: GasSetting,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let from = self.account_id(from)?;
+
+        let signers = TxSigners::Single(signer, signer.sign_mode());
+        let (sender, tx_body) = self.tx_body(&from, msgs, &signers.key())?;
+        self.sign_and_broadcast(&sender, tx_body, &signers, &FeeOptions::default(), gas)
+            .await
+    }
+
+    /// Returns the account sending a transaction of messages sent by `from`,
+    /// and the body of the transaction.
+    ///
+    /// In authz exec mode, messages of another account than the one of `key`
+    /// are wrapped in a `MsgExec` sent by the account of `key`, the grantee.
+    pub(crate) fn tx_body(
+        &self,
+        from: &AccountId,
+        msgs: Vec<Any>,
+        key: &TxKey,
+    ) -> Result<(AccountId, Body), RwaError> {
+        let signer = key.account_id(from.prefix())?;
+        if !self.authz_exec || signer == *from {
+            return Ok((from.clone(), tx::BodyBuilder::new().msgs(msgs).finish()));
+        }
+
+        let exec = authz::exec_msg(signer.as_ref(), msgs)?;
+        Ok((signer, tx::BodyBuilder::new().msg(exec).finish()))
+    }
+
+    /// Signs a transaction with the next sequence of the sender and broadcasts it.
+    ///
+    /// The sequence is reserved in the client's sequence cache, so concurrent
+    /// transactions of the same sender are signed with consecutive sequences
+    /// and broadcast without waiting for each other. A sequence the
+    /// transaction did not consume, e.g. because it was rejected during
+    /// `CheckTx`, is given back. A transaction rejected for an account
+    /// sequence mismatch, e.g. because the key was used elsewhere or a
+    /// concurrent transaction reached the node first, is retried after
+    /// resyncing the sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The account signing and paying for the transaction
+    /// * `tx_body` - The body of the transaction
+    /// * `signers` - The signer of the transaction, or the members of a multisig sender
+    /// * `fee` - The fee granter and payer, if the sender does not pay the fee
+    /// * `gas` - A fixed gas limit, or automatic estimation through simulation
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ExecuteResponse or an error
+    async fn sign_and_broadcast(
+        &self,
+        sender: &AccountId,
+        tx_body: Body,
+        signers: &TxSigners<'_>,
+        fee: &FeeOptions<'_>,
+        gas: GasSetting,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let mut retries = 0;
+
+        loop {
+            let account_info = self.reserve_sequence(sender).await?;
+
+            let signed = self
+                .sign_tx(sender, &tx_body, signers, fee, gas, account_info)
+                .await;
+            let broadcast = signed.is_ok();
+            let result = match signed {
+                Ok(tx_bytes) => self.broadcast(tx_bytes).await,
+                Err(error) => Err(error),
+            };
+
+            match &result {
+                // The sequence is consumed once the transaction passed CheckTx
+                Ok(_) | Err(RwaError::DeliverTx(_)) => {}
+                Err(error) if error.is_sequence_mismatch() => {
+                    // Resync from the chain unless the node told which sequence it expects
+                    let expected = error
+                        .tx_failure()
+                        .and_then(TxFailure::expected_sequence)
+                        .map(|sequence| AccountInfoResponse {
+                            sequence,
+                            ..account_info
+                        });
+                    self.sequences.resync(sender.as_ref(), expected).await;
+                }
+                Err(RwaError::Simulation(_) | RwaError::CheckTx(_)) => {
+                    self.sequences.release(sender.as_ref(), account_info).await;
+                }
+                Err(_) if !broadcast => {
+                    self.sequences.release(sender.as_ref(), account_info).await;
+                }
+                // Whether the node received the transaction is unknown
+                Err(_) => self.sequences.resync(sender.as_ref(), None).await,
+            }
+
+            match result {
+                Err(error) if error.is_sequence_mismatch() && retries < MAX_SEQUENCE_RETRIES => {
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Reserves the next sequence of an account, syncing it from the chain on first use.
+    async fn reserve_sequence(&self, sender: &AccountId) -> Result<AccountInfoResponse, RwaError> {
+        let mut account = self.sequences.lock(sender.as_ref()).await;
+        let account_info = match *account {
+            Some(account_info) => account_info,
+            None => self.fetch_account_info(sender).await?,
         };
+        *account = Some(AccountInfoResponse {
+            sequence: account_info.sequence + 1,
+            ..account_info
+        });
+        Ok(account_info)
+    }
 
-        let type_url = "/cosmwasm.wasm.v1.MsgExecuteContract".to_string();
-        let value = execute_msg.to_bytes()?;
-        let any_msg = cosmrs::Any { type_url, value };
+    /// Signs a transaction for a known account state into an encoded `TxRaw`.
+    ///
+    /// The sequence of a fee payer is read from the chain for every
+    /// transaction, so it must not sign other transactions concurrently.
+    async fn sign_tx(
+        &self,
+        sender: &AccountId,
+        tx_body: &Body,
+        signers: &TxSigners<'_>,
+        fee: &FeeOptions<'_>,
+        gas: GasSetting,
+        account_info: AccountInfoResponse,
+    ) -> Result<Vec<u8>, RwaError> {
+        let payer = match fee.payer {
+            Some(signer) => {
+                let account_id = signer.account_id(sender.prefix())?;
+                if account_id == *sender {
+                    return Err(RwaError::Config(format!(
+                        "the fee payer {} is the sender of the transaction",
+                        account_id
+                    )));
+                }
+                let account_info = self.fetch_account_info(&account_id).await?;
+                Some(FeePayer {
+                    signer,
+                    account_id,
+                    account_info,
+                })
+            }
+            None => None,
+        };
 
-        let tx_body = tx::BodyBuilder::new().msg(any_msg).finish();
+        let unsigned_tx = self
+            .build_tx(
+                tx_body,
+                &signers.key(),
+                fee.granter.as_ref(),
+                payer.as_ref(),
+                gas,
+                account_info,
+            )
+            .await?;
+        let mut tx_bytes = signers.sign(&unsigned_tx).await?;
+        if let Some(payer) = payer {
+            tx_bytes = unsigned_tx
+                .add_fee_payer_signature(
+                    &tx_bytes,
+                    payer.signer,
+                    payer.account_info.account_number,
+                    payer.account_info.sequence,
+                )
+                .await?;
+        }
 
-        let sender_account_id = AccountId::from_str(from)?;
-        let account_info = self.fetch_account_info(&sender_account_id).await?;
+        Ok(tx_bytes)
+    }
 
-        // Calculate fee based on user-specified gas limit
-        let fee_amount = gas_limit * self.gas_price;
-        let fee = Fee::from_amount_and_gas(
-            Coin {
-                amount: fee_amount.into(),
-                denom: self.denom.parse()?,
-            },
-            gas_limit,
-        );
+    /// Builds a transaction to be signed offline with the current sequence of the sender.
+    ///
+    /// The sequence is not consumed, since the transaction may never be signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The account signing and paying for the transaction
+    /// * `tx_body` - The body of the transaction
+    /// * `key` - The key of the offline signer, or the multisig account sending the transaction
+    /// * `fee_granter` - The account whose fee allowance pays the fee, the
+    ///   client's fee granter if `None`
+    /// * `gas` - A fixed gas limit, or automatic estimation through simulation
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the UnsignedTx or an error
+    pub(crate) async fn build_unsigned(
+        &self,
+        sender: &AccountId,
+        tx_body: Body,
+        key: &TxKey,
+        fee_granter: Option<&AccountId>,
+        gas: GasSetting,
+    ) -> Result<UnsignedTx, RwaError> {
+        let mut account = self.sequences.lock(sender.as_ref()).await;
+        let account_info = match *account {
+            Some(account_info) => account_info,
+            None => *account.insert(self.fetch_account_info(sender).await?),
+        };
 
-        // Prepare authentication info
-        let auth_info = SignerInfo::single_direct(Some(signer.public_key()), account_info.sequence)
-            .auth_info(fee);
+        self.build_tx(&tx_body, key, fee_granter, None, gas, account_info)
+            .await
+    }
 
-        // Construct the sign doc
-        let chain_id = Id::from_str(&self.chain_id)?;
-        let sign_doc = SignDoc::new(&tx_body, &auth_info, &chain_id, account_info.account_number)?;
+    /// Builds the fee and auth info of a transaction for a known account state.
+    async fn build_tx(
+        &self,
+        tx_body: &Body,
+        key: &TxKey,
+        fee_granter: Option<&AccountId>,
+        payer: Option<&FeePayer<'_>>,
+        gas: GasSetting,
+        account_info: AccountInfoResponse,
+    ) -> Result<UnsignedTx, RwaError> {
+        let fee_granter = fee_granter.or(self.fee_granter.as_ref()).cloned();
+        let fee_payer = payer.map(|payer| payer.account_id.clone());
 
-        let tx_raw = sign_doc.sign(signer)?;
+        let gas_limit = match gas {
+            GasSetting::Fixed(gas_limit) => gas_limit,
+            GasSetting::Auto { multiplier } => {
+                let fee = Fee {
+                    payer: fee_payer.clone(),
+                    granter: fee_granter.clone(),
+                    ..Fee::from_amount_and_gas(self.gas_prices[0].fee(0)?, 0u64)
+                };
+                let gas_used = self
+                    .simulate(tx_body, key, payer, fee, account_info.sequence)
+                    .await?;
+                self.gas_config.estimate(gas_used, multiplier)
+            }
+        };
 
-        let tx_bytes = tx_raw.to_bytes()?;
+        // Calculate fee based on the gas limit
+        let gas_price = self.gas_price().await?;
+        let fee = Fee {
+            payer: fee_payer,
+            granter: fee_granter,
+            ..Fee::from_amount_and_gas(gas_price.fee(gas_limit)?, gas_limit)
+        };
 
-        let response = self.rpc_client.broadcast_tx_commit(tx_bytes).await?;
+        // Prepare authentication info, the fee payer signing last
+        let mut signer_infos = vec![key.signer_info(account_info.sequence)];
+        signer_infos
+            .extend(payer.map(|payer| payer.key().signer_info(payer.account_info.sequence)));
+        let auth_info = AuthInfo { signer_infos, fee };
 
-        // Convert events from the response
-        let events: Vec<Event> = response
-            .tx_result
-            .events
-            .into_iter()
-            .map(|evt| Event {
-                kind: evt.kind,
-                attributes: evt.attributes,
-            })
-            .collect();
-
-        Ok(ExecuteResponse {
-            tx_hash: response.hash.to_string(),
-            data: response.tx_result.data.to_vec(),
-            gas_used: response.check_tx.gas_used,
-            gas_wanted: response.tx_result.gas_wanted,
-            events,
-            height: response.height.value(),
+        Ok(UnsignedTx {
+            chain_id: self.chain_id.to_string(),
+            account_number: account_info.account_number,
+            sequence: account_info.sequence,
+            body_bytes: tx_body
+                .clone()
+                .into_bytes()
+                .map_err(|e| RwaError::Encoding(e.to_string()))?
+                .into(),
+            auth_info_bytes: auth_info
+                .into_bytes()
+                .map_err(|e| RwaError::Encoding(e.to_string()))?
+                .into(),
         })
     }
 
+    /// Broadcasts a transaction signed elsewhere, e.g. with [`UnsignedTx::sign`].
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_bytes` - The encoded, signed `TxRaw`
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ExecuteResponse, or an error, as for any
+    /// operation sent with the client's broadcast mode
+    pub async fn broadcast_signed(&self, tx_bytes: &[u8]) -> Result<ExecuteResponse, RwaError> {
+        let tx_raw = TxRaw::decode(tx_bytes)?;
+        if tx_raw.signatures.is_empty() {
+            return Err(RwaError::Encoding(
+                "the transaction carries no signature".to_string(),
+            ));
+        }
+
+        self.broadcast(tx_bytes.to_vec()).await
+    }
+
+    /// Hands a signed transaction to the node according to the broadcast mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_bytes` - The encoded, signed transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ExecuteResponse, or an error. Unless the client
+    /// waits for the transaction to be committed, the response only carries the
+    /// transaction hash.
+    async fn broadcast(&self, tx_bytes: Vec<u8>) -> Result<ExecuteResponse, RwaError> {
+        match self.broadcast_mode {
+            BroadcastMode::Commit => {
+                let response = self.transport.broadcast_tx_commit(tx_bytes).await?;
+                let tx_hash = response.hash.to_string();
+
+                // A transaction rejected by the mempool never reaches a block
+                if response.check_tx.code.is_err() {
+                    return Err(RwaError::CheckTx(Box::new(TxFailure::new(
+                        tx_hash,
+                        0,
+                        response.check_tx.code.value(),
+                        response.check_tx.codespace,
+                        response.check_tx.log,
+                        response.check_tx.gas_wanted,
+                        response.check_tx.gas_used,
+                    ))));
+                }
+
+                committed_response(tx_hash, response.height.value(), response.tx_result)
+            }
+            BroadcastMode::Sync => {
+                let response = self.transport.broadcast_tx_sync(tx_bytes).await?;
+                let tx_hash = response.hash.to_string();
+
+                if response.code.is_err() {
+                    return Err(RwaError::CheckTx(Box::new(TxFailure::new(
+                        tx_hash,
+                        0,
+                        response.code.value(),
+                        response.codespace,
+                        response.log,
+                        0,
+                        0,
+                    ))));
+                }
+
+                Ok(ExecuteResponse::pending(tx_hash))
+            }
+            BroadcastMode::Async => {
+                let response = self.transport.broadcast_tx_async(tx_bytes).await?;
+                Ok(ExecuteResponse::pending(response.hash.to_string()))
+            }
+        }
+    }
+
+    /// Waits until a transaction is committed in a block.
+    ///
+    /// The transaction is looked up every `tx_poll_interval` until it is found
+    /// or `timeout` elapses. Use it to confirm transactions sent with
+    /// `BroadcastMode::Sync` or `BroadcastMode::Async`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hash` - The hash of the transaction, as returned in `ExecuteResponse::tx_hash`
+    /// * `timeout` - How long to wait for the transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the ExecuteResponse of the committed transaction, or
+    /// an error. A transaction failing during `DeliverTx` returns
+    /// `RwaError::DeliverTx`, and one not found in time returns `RwaError::TxTimeout`.
+    /// A failure of the node, other than not finding the transaction yet, is
+    /// returned at once as `RwaError::Transport`.
+    pub async fn wait_for_tx(
+        &self,
+        tx_hash: &str,
+        timeout: Duration,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let hash = Hash::from_str(&tx_hash.to_uppercase()).map_err(|e| {
+            RwaError::Encoding(format!("invalid transaction hash `{}`: {}", tx_hash, e))
+        })?;
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            // The node reports a transaction it has not committed yet as an error
+            match self.transport.tx(hash).await {
+                Ok(response) => {
+                    return committed_response(
+                        response.hash.to_string(),
+                        response.height.value(),
+                        response.tx_result,
+                    );
+                }
+                Err(e) if !is_tx_not_found(&e) => return Err(e),
+                Err(_) => {}
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RwaError::TxTimeout {
+                    tx_hash: tx_hash.to_string(),
+                    timeout,
+                });
+            }
+            tokio::time::sleep(self.tx_poll_interval.min(deadline - now)).await;
+        }
+    }
+
+    /// Simulates a transaction to determine its gas usage.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_body` - The body of the transaction to simulate
+    /// * `key` - The key signing the transaction
+    /// * `payer` - The fee payer co-signing the transaction, if any
+    /// * `fee` - The fee of the simulated transaction, naming its payer and granter
+    /// * `sequence` - The current sequence of the signer account
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the gas used by the simulated transaction or an error
+    async fn simulate(
+        &self,
+        tx_body: &Body,
+        key: &TxKey,
+        payer: Option<&FeePayer<'_>>,
+        fee: Fee,
+        sequence: u64,
+    ) -> Result<Gas, RwaError> {
+        let (signer_infos, signatures) = std::iter::once(key.simulation_signer(sequence))
+            .chain(payer.map(|payer| payer.key().simulation_signer(payer.account_info.sequence)))
+            .unzip();
+        let auth_info = AuthInfo { signer_infos, fee };
+
+        // Signatures are not verified during simulation, but one must be present
+        let tx_raw = TxRaw {
+            body_bytes: tx_body
+                .clone()
+                .into_bytes()
+                .map_err(|e| RwaError::Encoding(e.to_string()))?,
+            auth_info_bytes: auth_info
+                .into_bytes()
+                .map_err(|e| RwaError::Encoding(e.to_string()))?,
+            signatures,
+        };
+        let request = SimulateRequest {
+            tx_bytes: tx_raw.encode_to_vec(),
+            ..Default::default()
+        };
+
+        let path = "/cosmos.tx.v1beta1.Service/Simulate";
+
+        let response = self
+            .transport
+            .abci_query(path, request.encode_to_vec())
+            .await?;
+
+        if response.code.is_err() {
+            return Err(RwaError::Simulation(Box::new(TxFailure::new(
+                String::new(),
+                0,
+                response.code.value(),
+                response.codespace,
+                response.log,
+                0,
+                0,
+            ))));
+        }
+
+        let simulate_response = SimulateResponse::decode(response.value.as_slice())?;
+        let gas_info = simulate_response
+            .gas_info
+            .ok_or_else(|| RwaError::Encoding("simulation returned no gas info".to_string()))?;
+
+        Ok(gas_info.gas_used)
+    }
+
+    /// Queries the minimum gas prices accepted by the node.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the node's minimum gas prices, empty if it accepts
+    /// any fee, or an error
+    pub async fn min_gas_prices(&self) -> Result<Vec<GasPrice>, RwaError> {
+        let path = "/cosmos.base.node.v1beta1.Service/Config";
+
+        let response = self
+            .transport
+            .abci_query(path, ConfigRequest {}.encode_to_vec())
+            .await?;
+
+        if response.code.is_err() {
+            return Err(RwaError::query(path, &response));
+        }
+
+        let config = ConfigResponse::decode(response.value.as_slice())?;
+        GasPrice::parse_list(&config.minimum_gas_price).map_err(|e| match e {
+            RwaError::Config(reason) => RwaError::Encoding(format!(
+                "invalid minimum gas prices `{}` from the node: {}",
+                config.minimum_gas_price, reason
+            )),
+            e => e,
+        })
+    }
+
+    /// Selects the gas price used to pay the fee of a transaction.
+    ///
+    /// The first configured gas price is used, unless the client checks the
+    /// node's minimum gas prices: then the first configured denomination the
+    /// node accepts is used, priced at least at the node's minimum.
+    async fn gas_price(&self) -> Result<GasPrice, RwaError> {
+        if !self.query_min_gas_price {
+            return Ok(self.gas_prices[0].clone());
+        }
+
+        let minimums = self.min_gas_prices().await?;
+        if minimums.is_empty() {
+            return Ok(self.gas_prices[0].clone());
+        }
+
+        self.gas_prices
+            .iter()
+            .find_map(|gas_price| {
+                minimums
+                    .iter()
+                    .find(|minimum| minimum.denom == gas_price.denom)
+                    .map(|minimum| {
+                        if minimum.amount > gas_price.amount {
+                            minimum.clone()
+                        } else {
+                            gas_price.clone()
+                        }
+                    })
+            })
+            .ok_or_else(|| {
+                RwaError::Config(format!(
+                    "none of the configured fee denominations is accepted by the node, \
+                     minimum gas prices are {}",
+                    minimums
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ))
+            })
+    }
+
     /// Queries a contract without modifying the state.
     ///
     /// # Arguments
@@ -245,7 +931,30 @@ impl RwaClient {
         &self,
         contract_address: &str,
         msg: &impl serde::Serialize,
-    ) -> Result<T, Box<dyn std::error::Error>> {
+    ) -> Result<T, RwaError> {
+        let (result, _) = self.query_at_height(contract_address, msg, None).await?;
+        Ok(result)
+    }
+
+    /// Queries a contract without modifying the state, returning the height
+    /// of the block the query was answered at along with the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_address` - The address of the contract to query
+    /// * `msg` - The query message
+    /// * `height` - The height of the block whose state is queried, the
+    ///   latest if `None`
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the deserialized response and the block height, or an error
+    async fn query_at_height<T: serde::de::DeserializeOwned>(
+        &self,
+        contract_address: &str,
+        msg: &impl serde::Serialize,
+        height: Option<u64>,
+    ) -> Result<(T, u64), RwaError> {
         let query_msg = cosmwasm_std::to_json_binary(&msg)?;
         let query_data = cosmrs::proto::cosmwasm::wasm::v1::QuerySmartContractStateRequest {
             address: contract_address.to_string(),
@@ -255,41 +964,185 @@ impl RwaClient {
 
         let path = "/cosmwasm.wasm.v1.Query/SmartContractState";
 
-        let response = self
-            .rpc_client
-            .abci_query(Some(path.to_string()), query_data, None, false)
-            .await?;
+        let response = match height {
+            Some(height) => {
+                self.transport
+                    .abci_query_at(path, query_data, height)
+                    .await?
+            }
+            None => self.transport.abci_query(path, query_data).await?,
+        };
 
-        let abci_response = AbciQueryResponse::decode(response.value.as_slice())?;
-        let result: T = cosmwasm_std::from_json(&abci_response.value)?;
-        Ok(result)
+        if response.code.is_err() {
+            return Err(RwaError::Contract(response.log));
+        }
+
+        let query_response = QuerySmartContractStateResponse::decode(response.value.as_slice())?;
+        let result: T = cosmwasm_std::from_json(&query_response.data)?;
+        Ok((result, response.height.value()))
     }
 
     /// Fetches account information for a given account ID.
     ///
+    /// The account is queried through the auth module's `Account` query and
+    /// decoded according to its type. An account of a type the client does
+    /// not know is resolved through the `AccountInfo` query instead, where
+    /// the node supports it (Cosmos SDK 0.47 and later).
+    ///
     /// # Arguments
     ///
     /// * `account_id` - The AccountId to fetch information for
     ///
     /// # Returns
     ///
-    /// A Result containing an AccountInfoResponse or an error
+    /// A Result containing an AccountInfoResponse, or an error.
+    /// `RwaError::AccountNotFound` is returned for an account that does not
+    /// exist on chain yet.
     async fn fetch_account_info(
         &self,
         account_id: &AccountId,
-    ) -> Result<AccountInfoResponse, Box<dyn std::error::Error>> {
-        let path = format!("/cosmos/auth/v1beta1/accounts/{}", account_id);
+    ) -> Result<AccountInfoResponse, RwaError> {
+        let request = QueryAccountRequest {
+            address: account_id.to_string(),
+        };
+        let path = "/cosmos.auth.v1beta1.Query/Account";
+
         let data = self
-            .rpc_client
-            .abci_query(Some(path), Vec::new(), None, false)
+            .transport
+            .abci_query(path, request.encode_to_vec())
             .await?;
+        account_query_result(account_id, &data)?;
 
-        let any = Any::decode(data.value.as_slice())?;
-        let account = BaseAccount::decode(any.value.as_slice())?;
+        let any = QueryAccountResponse::decode(data.value.as_slice())
+            .map_err(|e| RwaError::account_lookup(account_id, e))?
+            .account
+            .ok_or_else(|| RwaError::AccountNotFound(account_id.to_string()))?;
+        let account = match self.account_types.base_account(account_id, &any) {
+            Ok(account) => account,
+            Err(error) => self
+                .query_account_info(account_id)
+                .await
+                .map_err(|_| error)?,
+        };
 
         Ok(AccountInfoResponse {
             account_number: account.account_number,
             sequence: account.sequence,
         })
     }
+
+    /// Queries the base account of an account of any type through the
+    /// `AccountInfo` query.
+    async fn query_account_info(&self, account_id: &AccountId) -> Result<BaseAccount, RwaError> {
+        let request = QueryAccountInfoRequest {
+            address: account_id.to_string(),
+        };
+        let path = "/cosmos.auth.v1beta1.Query/AccountInfo";
+
+        let data = self
+            .transport
+            .abci_query(path, request.encode_to_vec())
+            .await?;
+        account_query_result(account_id, &data)?;
+
+        QueryAccountInfoResponse::decode(data.value.as_slice())
+            .map_err(|e| RwaError::account_lookup(account_id, e))?
+            .info
+            .ok_or_else(|| RwaError::AccountNotFound(account_id.to_string()))
+    }
+}
+
+/// Checks the result code of an account query.
+fn account_query_result(account_id: &AccountId, data: &AbciQuery) -> Result<(), RwaError> {
+    if data.code.is_err() {
+        if data.code.value() == account::KEY_NOT_FOUND_CODE && data.codespace == "sdk" {
+            return Err(RwaError::AccountNotFound(account_id.to_string()));
+        }
+        return Err(RwaError::account_lookup(account_id, &data.log));
+    }
+    Ok(())
+}
+
+/// Builds a `MsgExecuteContract` message calling `contract` with `msg`.
+///
+/// # Returns
+///
+/// A Result containing the message packed into an `Any`, or an error if the
+/// message could not be encoded
+fn execute_contract_msg<T: serde::Serialize>(
+    from: &str,
+    contract: &str,
+    msg: &T,
+    funds: Vec<Coin>,
+) -> Result<Any, RwaError> {
+    let execute_msg = MsgExecuteContract {
+        sender: from.to_string(),
+        contract: contract.to_string(),
+        msg: cosmwasm_std::to_json_binary(msg)?.into(),
+        funds: funds.into_iter().map(|c| c.into()).collect(),
+    };
+
+    let type_url = "/cosmwasm.wasm.v1.MsgExecuteContract".to_string();
+    let value = execute_msg
+        .to_bytes()
+        .map_err(|e| RwaError::Encoding(e.to_string()))?;
+    Ok(Any { type_url, value })
+}
+
+/// Returns whether a transaction lookup failed only because the node has not
+/// committed the transaction yet.
+fn is_tx_not_found(error: &RwaError) -> bool {
+    let RwaError::Transport(error) = error else {
+        return false;
+    };
+    match error.detail() {
+        ErrorDetail::Response(e) => e
+            .source
+            .data()
+            .is_some_and(|data| data.contains("not found")),
+        _ => false,
+    }
+}
+
+/// Builds the response of a committed transaction.
+///
+/// # Returns
+///
+/// A Result containing the ExecuteResponse, or `RwaError::DeliverTx` if the
+/// execution of the transaction failed
+fn committed_response(
+    tx_hash: String,
+    height: u64,
+    tx_result: ExecTxResult,
+) -> Result<ExecuteResponse, RwaError> {
+    if tx_result.code.is_err() {
+        return Err(RwaError::DeliverTx(Box::new(TxFailure::new(
+            tx_hash,
+            height,
+            tx_result.code.value(),
+            tx_result.codespace,
+            tx_result.log,
+            tx_result.gas_wanted,
+            tx_result.gas_used,
+        ))));
+    }
+
+    // Convert events from the response
+    let events: Vec<Event> = tx_result
+        .events
+        .into_iter()
+        .map(|evt| Event {
+            kind: evt.kind,
+            attributes: evt.attributes,
+        })
+        .collect();
+
+    Ok(ExecuteResponse {
+        tx_hash,
+        data: tx_result.data.to_vec(),
+        gas_used: tx_result.gas_used,
+        gas_wanted: tx_result.gas_wanted,
+        events,
+        height,
+    })
 }
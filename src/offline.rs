@@ -0,0 +1,320 @@
+//! Offline signing for the RWA SDK.
+//!
+//! [`RwaClient`] usually builds, signs and broadcasts a transaction in one
+//! call. When the key lives on an air-gapped machine the steps are split:
+//!
+//! 1. an online machine builds an [`UnsignedTx`] for any operation, through
+//!    [`TxBatch::build_unsigned`], and serializes it, e.g. to a JSON file;
+//! 2. the offline machine signs it with [`UnsignedTx::sign`], producing the
+//!    encoded `TxRaw`;
+//! 3. the online machine broadcasts the signed bytes with
+//!    [`RwaClient::broadcast_signed`].
+//!
+//! ```rust,no_run
+//! use erc3643sdk::{GasSetting, LocalSigner, RwaClient, Signer, UnsignedTx};
+//!
+//! # async fn run(client: RwaClient, offline_signer: LocalSigner) -> Result<(), Box<dyn std::error::Error>> {
+//! // Online: only the public key of the offline signer is needed
+//! let unsigned = client
+//!     .batch(&"kii1issuer...".parse()?)
+//!     .transfer(&"kii1investor...".parse()?, 1_000)
+//!     .build_unsigned(offline_signer.public_key(), GasSetting::default())
+//!     .await?;
+//! std::fs::write("transfer.json", serde_json::to_vec(&unsigned)?)?;
+//!
+//! // Offline
+//! let unsigned: UnsignedTx = serde_json::from_slice(&std::fs::read("transfer.json")?)?;
+//! let tx_bytes = unsigned.sign(&offline_signer).await?;
+//!
+//! // Online again
+//! let response = client.broadcast_signed(&tx_bytes).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The transaction is bound to the account sequence current when it was
+//! built, so other transactions of the same account must not be sent before
+//! it is broadcast.
+//!
+//! A transaction of a [`MultisigAccount`] is built with
+//! [`TxBatch::build_multisig`]. Each member signs it with
+//! [`UnsignedTx::sign_partial`], and the [`PartialSignature`] files are
+//! combined into the signed transaction with [`UnsignedTx::combine`].
+//!
+//! [`RwaClient`]: crate::RwaClient
+//! [`RwaClient::broadcast_signed`]: crate::RwaClient::broadcast_signed
+//! [`TxBatch::build_unsigned`]: crate::TxBatch::build_unsigned
+//! [`TxBatch::build_multisig`]: crate::TxBatch::build_multisig
+
+use cosmrs::proto::cosmos::tx::v1beta1::{AuthInfo as ProtoAuthInfo, TxBody as ProtoTxBody, TxRaw};
+use cosmrs::proto::prost::Message;
+use cosmrs::tx::{AuthInfo, Body, ModeInfo, SignDoc, SignMode as ProtoSignMode, SignerPublicKey};
+use cosmwasm_std::Binary;
+use k256::ecdsa::signature::Verifier;
+use k256::ecdsa::{Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+use crate::{amino, KeyType, MultisigAccount, PartialSignature, RwaError, SignMode, Signer};
+
+/// A transaction ready to be signed, without access to the chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsignedTx {
+    /// The chain the transaction is valid on
+    pub chain_id: String,
+    /// The account number of the signer
+    pub account_number: u64,
+    /// The account sequence the transaction is signed with
+    pub sequence: u64,
+    /// The protobuf encoded `TxBody`, holding the messages
+    pub body_bytes: Binary,
+    /// The protobuf encoded `AuthInfo`, holding the signer and the fee
+    pub auth_info_bytes: Binary,
+}
+
+impl UnsignedTx {
+    /// Decodes the body of the transaction, e.g. to review its messages before signing.
+    pub fn body(&self) -> Result<Body, RwaError> {
+        Body::try_from(ProtoTxBody::decode(self.body_bytes.as_slice())?)
+            .map_err(|e| RwaError::Encoding(e.to_string()))
+    }
+
+    /// Decodes the auth info of the transaction, e.g. to review its fee before signing.
+    pub fn auth_info(&self) -> Result<AuthInfo, RwaError> {
+        AuthInfo::try_from(ProtoAuthInfo::decode(self.auth_info_bytes.as_slice())?)
+            .map_err(|e| RwaError::Encoding(e.to_string()))
+    }
+
+    /// Returns the sign mode the transaction was built for.
+    ///
+    /// The members of a multisig account sign in `SignMode::AminoJson`.
+    pub fn sign_mode(&self) -> Result<SignMode, RwaError> {
+        let mode = self.auth_info()?.signer_infos.first().map(|signer_info| {
+            match &signer_info.mode_info {
+                ModeInfo::Single(single) => single.mode,
+                ModeInfo::Multi(_) => ProtoSignMode::LegacyAminoJson,
+            }
+        });
+
+        match mode {
+            Some(ProtoSignMode::Direct) => Ok(SignMode::Direct),
+            Some(ProtoSignMode::LegacyAminoJson) => Ok(SignMode::AminoJson),
+            mode => Err(RwaError::Signing(format!(
+                "unsupported sign mode {:?}",
+                mode
+            ))),
+        }
+    }
+
+    /// Returns the bytes the signer signs: the encoded `SignDoc`, or the
+    /// `StdSignDoc` for Amino JSON signers.
+    pub fn sign_bytes(&self) -> Result<Vec<u8>, RwaError> {
+        self.sign_bytes_as(self.sign_mode()?, self.account_number, self.sequence)
+    }
+
+    /// Returns the bytes signed by an account of the transaction in `sign_mode`.
+    fn sign_bytes_as(
+        &self,
+        sign_mode: SignMode,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Vec<u8>, RwaError> {
+        if sign_mode == SignMode::AminoJson {
+            return amino::sign_bytes(
+                &self.body()?,
+                &self.auth_info()?,
+                &self.chain_id,
+                account_number,
+                sequence,
+            );
+        }
+
+        let chain_id = self
+            .chain_id
+            .parse()
+            .map_err(|e| RwaError::Encoding(format!("invalid chain id: {}", e)))?;
+
+        SignDoc {
+            body_bytes: self.body_bytes.to_vec(),
+            auth_info_bytes: self.auth_info_bytes.to_vec(),
+            chain_id,
+            account_number,
+        }
+        .into_bytes()
+        .map_err(|e| RwaError::Encoding(e.to_string()))
+    }
+
+    /// Signs the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - The signer the transaction was built for
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the encoded `TxRaw`, ready for
+    /// [`RwaClient::broadcast_signed`](crate::RwaClient::broadcast_signed), or
+    /// an error if the transaction was built for another key
+    pub async fn sign(&self, signer: &dyn Signer) -> Result<Vec<u8>, RwaError> {
+        let signer_key = signer.key_type().signer_public_key(signer.public_key());
+        match self.signer_key()? {
+            key if key == signer_key => {}
+            SignerPublicKey::LegacyAminoMultisig(_) => {
+                return Err(RwaError::Signing(
+                    "the transaction was built for a multisig account, sign it with \
+                     `UnsignedTx::sign_partial`"
+                        .to_string(),
+                ))
+            }
+            _ => {
+                return Err(RwaError::Signing(
+                    "the transaction was built for another public key".to_string(),
+                ))
+            }
+        }
+
+        let signature = signer.sign(&self.sign_bytes()?).await?;
+
+        Ok(TxRaw {
+            body_bytes: self.body_bytes.to_vec(),
+            auth_info_bytes: self.auth_info_bytes.to_vec(),
+            signatures: vec![signature],
+        }
+        .encode_to_vec())
+    }
+
+    /// Signs the transaction as one member of the multisig account it was built for.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - The signer of a member of the multisig account
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the PartialSignature, to be combined with the
+    /// signatures of the other members, or an error if the signer is not a member
+    pub async fn sign_partial(&self, signer: &dyn Signer) -> Result<PartialSignature, RwaError> {
+        let multisig = self.multisig()?;
+        let public_key = signer.public_key();
+        if signer.key_type() != KeyType::Secp256k1 || !multisig.public_keys().contains(&public_key)
+        {
+            return Err(RwaError::Signing(
+                "the signer is not a member of the multisig account".to_string(),
+            ));
+        }
+
+        let signature = signer.sign(&self.sign_bytes()?).await?;
+        Ok(PartialSignature {
+            public_key: public_key.to_bytes().into(),
+            signature: signature.into(),
+        })
+    }
+
+    /// Combines the partial signatures of multisig members into a signed transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - The partial signatures, in any order
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the encoded `TxRaw`, ready for
+    /// [`RwaClient::broadcast_signed`](crate::RwaClient::broadcast_signed), or
+    /// an error if a signature is invalid or fewer than `threshold` members signed
+    pub fn combine(&self, signatures: &[PartialSignature]) -> Result<Vec<u8>, RwaError> {
+        let multisig = self.multisig()?;
+        let sign_bytes = self.sign_bytes()?;
+
+        let mut member_signatures = vec![None; multisig.public_keys().len()];
+        for partial in signatures {
+            let index = multisig
+                .public_keys()
+                .iter()
+                .position(|public_key| public_key.to_bytes() == partial.public_key.as_slice())
+                .ok_or_else(|| {
+                    RwaError::Signing(format!(
+                        "{} is not a member of the multisig account",
+                        partial.public_key
+                    ))
+                })?;
+            verify(partial, &sign_bytes).map_err(|e| {
+                RwaError::Signing(format!(
+                    "invalid signature of multisig member {}: {}",
+                    index, e
+                ))
+            })?;
+            member_signatures[index] = Some(partial.signature.to_vec());
+        }
+
+        let signed: Vec<bool> = member_signatures.iter().map(Option::is_some).collect();
+        let count = signed.iter().filter(|signed| **signed).count();
+        if count < multisig.threshold() as usize {
+            return Err(RwaError::Signing(format!(
+                "{} of the {} required multisig signatures were given",
+                count,
+                multisig.threshold()
+            )));
+        }
+
+        let mut auth_info = self.auth_info()?;
+        auth_info.signer_infos[0] = multisig.signer_info(&signed, self.sequence);
+
+        Ok(TxRaw {
+            body_bytes: self.body_bytes.to_vec(),
+            auth_info_bytes: auth_info
+                .into_bytes()
+                .map_err(|e| RwaError::Encoding(e.to_string()))?,
+            signatures: vec![MultisigAccount::multi_signature(
+                member_signatures.into_iter().flatten().collect(),
+            )],
+        }
+        .encode_to_vec())
+    }
+
+    /// Adds the signature of the fee payer to a transaction signed by its sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_bytes` - The encoded `TxRaw` signed by the sender
+    /// * `signer` - The signer of the fee payer, the last signer of the transaction
+    /// * `account_number` - The account number of the fee payer
+    /// * `sequence` - The account sequence of the fee payer
+    pub(crate) async fn add_fee_payer_signature(
+        &self,
+        tx_bytes: &[u8],
+        signer: &dyn Signer,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Vec<u8>, RwaError> {
+        let sign_bytes = self.sign_bytes_as(signer.sign_mode(), account_number, sequence)?;
+        let mut tx_raw = TxRaw::decode(tx_bytes)?;
+        tx_raw.signatures.push(signer.sign(&sign_bytes).await?);
+        Ok(tx_raw.encode_to_vec())
+    }
+
+    /// Returns the public key declared as the signer of the transaction.
+    fn signer_key(&self) -> Result<SignerPublicKey, RwaError> {
+        self.auth_info()?
+            .signer_infos
+            .into_iter()
+            .next()
+            .and_then(|signer_info| signer_info.public_key)
+            .ok_or_else(|| RwaError::Encoding("the transaction declares no signer".to_string()))
+    }
+
+    /// Returns the multisig account the transaction was built for.
+    fn multisig(&self) -> Result<MultisigAccount, RwaError> {
+        match self.signer_key()? {
+            SignerPublicKey::LegacyAminoMultisig(key) => Ok(MultisigAccount::from_key(key)),
+            _ => Err(RwaError::Signing(
+                "the transaction was not built for a multisig account".to_string(),
+            )),
+        }
+    }
+}
+
+/// Verifies the signature of a multisig member over the sign bytes.
+fn verify(partial: &PartialSignature, sign_bytes: &[u8]) -> Result<(), k256::ecdsa::Error> {
+    let public_key = VerifyingKey::from_sec1_bytes(partial.public_key.as_slice())?;
+    let signature = Signature::from_slice(partial.signature.as_slice())?;
+    public_key.verify(sign_bytes, &signature)
+}
@@ -0,0 +1,66 @@
+//! Local account sequence cache for the RWA SDK.
+//!
+//! Every transaction must be signed with the next sequence of its sender. The
+//! cache remembers the account number and next sequence of each sender, so the
+//! chain is only queried once per account, and hands them out under a
+//! per-account lock so concurrent callers never sign with the same sequence.
+//!
+//! The cache holds up to `MAX_CACHED_ACCOUNTS` accounts: beyond that,
+//! accounts with no transaction in progress are evicted, and synced from the
+//! chain again on their next transaction.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+use crate::AccountInfoResponse;
+
+/// Number of accounts cached before idle accounts are evicted.
+const MAX_CACHED_ACCOUNTS: usize = 1024;
+
+/// The cached state of one account, `None` until it is synced from the chain.
+pub(crate) type AccountSlot = Option<AccountInfoResponse>;
+
+/// Per-account sequence cache shared by all clones of a client.
+#[derive(Debug, Default)]
+pub(crate) struct SequenceCache {
+    accounts: Mutex<HashMap<String, Arc<AsyncMutex<AccountSlot>>>>,
+}
+
+impl SequenceCache {
+    /// Locks the cached state of an account.
+    ///
+    /// The lock is only held while a sequence is reserved or given back, so
+    /// the transactions of one account are signed with consecutive sequences
+    /// and broadcast concurrently, while different accounts proceed
+    /// independently.
+    pub(crate) async fn lock(&self, address: &str) -> OwnedMutexGuard<AccountSlot> {
+        let slot = {
+            let mut accounts = self.accounts.lock().unwrap();
+            if accounts.len() >= MAX_CACHED_ACCOUNTS && !accounts.contains_key(address) {
+                // Only the map refers to the slot of an account nobody is sending from
+                accounts.retain(|_, slot| Arc::strong_count(slot) > 1);
+            }
+            accounts.entry(address.to_string()).or_default().clone()
+        };
+        slot.lock_owned().await
+    }
+
+    /// Gives back a sequence reserved by a transaction that did not consume it.
+    ///
+    /// If a later sequence has been reserved since, the cache is left as is:
+    /// the transaction signed with it fails with a sequence mismatch, which
+    /// resyncs the account.
+    pub(crate) async fn release(&self, address: &str, account_info: AccountInfoResponse) {
+        let mut slot = self.lock(address).await;
+        if slot.is_some_and(|cached| cached.sequence == account_info.sequence + 1) {
+            *slot = Some(account_info);
+        }
+    }
+
+    /// Replaces the cached state of an account, `None` to sync it from the chain again.
+    pub(crate) async fn resync(&self, address: &str, account_info: AccountSlot) {
+        *self.lock(address).await = account_info;
+    }
+}
@@ -0,0 +1,438 @@
+//! Encrypted on-disk keystore.
+//!
+//! Every key is stored in its own `<name>.json` file in the keystore
+//! directory. The private key is encrypted with AES-256-GCM under a key
+//! derived from the password with scrypt. The public key is stored in clear,
+//! so keys can be listed without their password. Binary values are base64
+//! encoded.
+//!
+//! ```text
+//! {
+//!   "version": 1,
+//!   "name": "issuer",
+//!   "public_key": "A+3Y...",
+//!   "key_type": "secp256k1",
+//!   "crypto": {
+//!     "kdf": "scrypt", "log_n": 15, "r": 8, "p": 1, "salt": "9mWl...",
+//!     "cipher": "aes-256-gcm", "nonce": "Hq4r...", "ciphertext": "kq0Z..."
+//!   }
+//! }
+//! ```
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use cosmrs::crypto::secp256k1::VerifyingKey;
+use cosmrs::crypto::PublicKey;
+use cosmrs::AccountId;
+use cosmwasm_std::Binary;
+use serde::{Deserialize, Serialize};
+use serde_json::error::Category;
+use zeroize::Zeroizing;
+
+use super::{HdPath, KeyType, LocalSigner, Signer, Wallet};
+use crate::RwaError;
+
+/// Default scrypt cost parameter, `N = 2^15`.
+pub const DEFAULT_SCRYPT_LOG_N: u8 = 15;
+
+/// Version of the key file format.
+const KEY_FILE_VERSION: u32 = 1;
+
+/// Key derivation function of the key files.
+const KDF: &str = "scrypt";
+
+/// Cipher of the key files.
+const CIPHER: &str = "aes-256-gcm";
+
+/// scrypt block size parameter.
+const SCRYPT_R: u32 = 8;
+
+/// scrypt parallelization parameter.
+const SCRYPT_P: u32 = 1;
+
+/// Length of the random scrypt salt.
+const SALT_LEN: usize = 32;
+
+/// Length of an AES-GCM nonce.
+const NONCE_LEN: usize = 12;
+
+/// Extension of the key files.
+const KEY_FILE_EXTENSION: &str = "json";
+
+/// A directory of named secp256k1 and eth_secp256k1 keys, encrypted with a password.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use erc3643sdk::Keystore;
+///
+/// # fn main() -> Result<(), erc3643sdk::RwaError> {
+/// let keystore = Keystore::open("/etc/rwa/keys")?;
+/// keystore.import("issuer", &[/* your private key */], "password")?;
+///
+/// let signer = keystore.signer("issuer", "password")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keystore {
+    dir: PathBuf,
+    scrypt_log_n: u8,
+}
+
+/// A key stored in a [`Keystore`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyInfo {
+    /// The name of the key
+    pub name: String,
+    /// The public key
+    pub public_key: PublicKey,
+    /// The type of the key
+    pub key_type: KeyType,
+}
+
+impl KeyInfo {
+    /// Returns the account address of the key.
+    pub fn account_id(&self, prefix: &str) -> Result<AccountId, RwaError> {
+        self.key_type.account_id(&self.public_key, prefix)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyFile {
+    version: u32,
+    name: String,
+    public_key: Binary,
+    // Key files written before `eth_secp256k1` keys were supported hold
+    // `secp256k1` keys
+    #[serde(default)]
+    key_type: KeyType,
+    crypto: Crypto,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Crypto {
+    kdf: String,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: Binary,
+    cipher: String,
+    nonce: Binary,
+    ciphertext: Binary,
+}
+
+impl Keystore {
+    /// Opens a keystore, creating its directory if it does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory holding the key files
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the Keystore, or an error if the directory cannot be created
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, RwaError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)
+            .map_err(|e| RwaError::Keystore(format!("cannot create {}: {}", dir.display(), e)))?;
+
+        Ok(Self {
+            dir,
+            scrypt_log_n: DEFAULT_SCRYPT_LOG_N,
+        })
+    }
+
+    /// Sets the scrypt cost used to encrypt new keys, `N = 2^log_n`.
+    ///
+    /// Existing keys are decrypted with the cost they were stored with.
+    pub fn with_scrypt_log_n(mut self, log_n: u8) -> Self {
+        self.scrypt_log_n = log_n;
+        self
+    }
+
+    /// Returns the directory of the keystore.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Lists the keys of the keystore, sorted by name.
+    ///
+    /// JSON documents of the directory that are not key files are skipped;
+    /// an unreadable or corrupted key file fails the listing.
+    pub fn list(&self) -> Result<Vec<KeyInfo>, RwaError> {
+        let entries = fs::read_dir(&self.dir).map_err(|e| {
+            RwaError::Keystore(format!("cannot read {}: {}", self.dir.display(), e))
+        })?;
+
+        let mut keys = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| {
+                    RwaError::Keystore(format!("cannot read {}: {}", self.dir.display(), e))
+                })?
+                .path();
+            if path
+                .extension()
+                .is_some_and(|ext| ext == KEY_FILE_EXTENSION)
+            {
+                if let Some(key_file) = read_key_file(&path)? {
+                    keys.push(key_info(&key_file)?);
+                }
+            }
+        }
+        keys.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(keys)
+    }
+
+    /// Returns a key of the keystore.
+    pub fn get(&self, name: &str) -> Result<KeyInfo, RwaError> {
+        key_info(&self.load(name)?)
+    }
+
+    /// Encrypts a private key and stores it under a new name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the key, made of ASCII letters, digits, `-` and `_`
+    /// * `private_key` - The raw bytes of the secp256k1 private key
+    /// * `password` - The password encrypting the key
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the stored key, or an error if the name is taken
+    pub fn import(
+        &self,
+        name: &str,
+        private_key: &[u8],
+        password: &str,
+    ) -> Result<KeyInfo, RwaError> {
+        self.import_with_key_type(name, private_key, KeyType::Secp256k1, password)
+    }
+
+    /// Same as [`Keystore::import`], for a private key of the given type,
+    /// e.g. the `eth_secp256k1` key of an EVM account.
+    pub fn import_with_key_type(
+        &self,
+        name: &str,
+        private_key: &[u8],
+        key_type: KeyType,
+        password: &str,
+    ) -> Result<KeyInfo, RwaError> {
+        let path = self.key_path(name)?;
+        let public_key = LocalSigner::from_slice(private_key)?.public_key();
+
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let key = derive_key(password, &salt, self.scrypt_log_n, SCRYPT_R, SCRYPT_P)?;
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = Aes256Gcm::new(&key)
+            .encrypt(&nonce, private_key)
+            .map_err(|_| RwaError::Keystore(format!("cannot encrypt key `{}`", name)))?;
+
+        let key_file = KeyFile {
+            version: KEY_FILE_VERSION,
+            name: name.to_string(),
+            public_key: Binary::from(public_key.to_bytes()),
+            key_type,
+            crypto: Crypto {
+                kdf: KDF.to_string(),
+                log_n: self.scrypt_log_n,
+                r: SCRYPT_R,
+                p: SCRYPT_P,
+                salt: Binary::from(salt.as_slice()),
+                cipher: CIPHER.to_string(),
+                nonce: Binary::from(nonce.as_slice()),
+                ciphertext: Binary::from(ciphertext),
+            },
+        };
+        write_key_file(&path, &key_file)?;
+
+        Ok(KeyInfo {
+            name: name.to_string(),
+            public_key,
+            key_type,
+        })
+    }
+
+    /// Derives a key from a mnemonic wallet and stores it under a new name,
+    /// with the key type of the derivation path.
+    pub fn import_mnemonic(
+        &self,
+        name: &str,
+        wallet: &Wallet,
+        path: &HdPath,
+        password: &str,
+    ) -> Result<KeyInfo, RwaError> {
+        self.import_with_key_type(
+            name,
+            &wallet.derive_secret(path)?,
+            path.key_type(),
+            password,
+        )
+    }
+
+    /// Decrypts a key and returns its raw private key bytes.
+    pub fn export(&self, name: &str, password: &str) -> Result<Zeroizing<Vec<u8>>, RwaError> {
+        let key_file = self.load(name)?;
+        let crypto = &key_file.crypto;
+        if crypto.kdf != KDF || crypto.cipher != CIPHER {
+            return Err(RwaError::Keystore(format!(
+                "key `{}` uses unsupported kdf `{}` or cipher `{}`",
+                name, crypto.kdf, crypto.cipher
+            )));
+        }
+        if crypto.nonce.len() != NONCE_LEN {
+            return Err(RwaError::Keystore(format!(
+                "key `{}` has an invalid nonce",
+                name
+            )));
+        }
+
+        let key = derive_key(password, &crypto.salt, crypto.log_n, crypto.r, crypto.p)?;
+        let private_key = Aes256Gcm::new(&key)
+            .decrypt(
+                Nonce::from_slice(crypto.nonce.as_slice()),
+                crypto.ciphertext.as_slice(),
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| RwaError::Keystore(format!("wrong password for key `{}`", name)))?;
+
+        let public_key = LocalSigner::from_slice(&private_key)?.public_key();
+        if public_key.to_bytes() != key_file.public_key.as_slice() {
+            return Err(RwaError::Keystore(format!(
+                "key `{}` does not match its public key",
+                name
+            )));
+        }
+        Ok(private_key)
+    }
+
+    /// Decrypts a key into a signer usable by [`RwaClient`](crate::RwaClient) operations.
+    pub fn signer(&self, name: &str, password: &str) -> Result<LocalSigner, RwaError> {
+        let key_type = self.load(name)?.key_type;
+        LocalSigner::from_slice_with_key_type(&self.export(name, password)?, key_type)
+    }
+
+    /// Deletes a key from the keystore.
+    pub fn delete(&self, name: &str) -> Result<(), RwaError> {
+        let path = self.key_path(name)?;
+        fs::remove_file(&path).map_err(|e| key_file_error(name, &path, e))
+    }
+
+    /// Reads the file of a key.
+    fn load(&self, name: &str) -> Result<KeyFile, RwaError> {
+        let path = self.key_path(name)?;
+        if !path.exists() {
+            return Err(RwaError::Keystore(format!("no key named `{}`", name)));
+        }
+        read_key_file(&path)?
+            .ok_or_else(|| RwaError::Keystore(format!("{} is not a key file", path.display())))
+    }
+
+    /// Returns the path of the file of a key.
+    fn key_path(&self, name: &str) -> Result<PathBuf, RwaError> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(RwaError::Keystore(format!(
+                "invalid key name `{}`, expected ASCII letters, digits, `-` or `_`",
+                name
+            )));
+        }
+
+        Ok(self.dir.join(format!("{}.{}", name, KEY_FILE_EXTENSION)))
+    }
+}
+
+/// Derives the AES-256 key from a password with scrypt.
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    log_n: u8,
+    r: u32,
+    p: u32,
+) -> Result<Zeroizing<Key<Aes256Gcm>>, RwaError> {
+    let params = scrypt::Params::new(log_n, r, p, 32)
+        .map_err(|e| RwaError::Keystore(format!("invalid scrypt parameters: {}", e)))?;
+    let mut key = Zeroizing::new(Key::<Aes256Gcm>::default());
+    scrypt::scrypt(password.as_bytes(), salt, &params, key.as_mut_slice())
+        .map_err(|e| RwaError::Keystore(format!("key derivation failed: {}", e)))?;
+    Ok(key)
+}
+
+fn key_info(key_file: &KeyFile) -> Result<KeyInfo, RwaError> {
+    let public_key =
+        VerifyingKey::from_sec1_bytes(key_file.public_key.as_slice()).map_err(|e| {
+            RwaError::Keystore(format!(
+                "key `{}` has an invalid public key: {}",
+                key_file.name, e
+            ))
+        })?;
+
+    Ok(KeyInfo {
+        name: key_file.name.clone(),
+        public_key: public_key.into(),
+        key_type: key_file.key_type,
+    })
+}
+
+/// Reads a key file, or returns `None` for a well-formed JSON document that is
+/// not a key file.
+fn read_key_file(path: &Path) -> Result<Option<KeyFile>, RwaError> {
+    let contents = fs::read(path)
+        .map_err(|e| RwaError::Keystore(format!("cannot read {}: {}", path.display(), e)))?;
+    let key_file: KeyFile = match serde_json::from_slice(&contents) {
+        Ok(key_file) => key_file,
+        Err(e) if e.classify() == Category::Data => return Ok(None),
+        Err(e) => {
+            return Err(RwaError::Keystore(format!(
+                "invalid key file {}: {}",
+                path.display(),
+                e
+            )))
+        }
+    };
+
+    if key_file.version != KEY_FILE_VERSION {
+        return Err(RwaError::Keystore(format!(
+            "unsupported version {} of key file {}",
+            key_file.version,
+            path.display()
+        )));
+    }
+    Ok(Some(key_file))
+}
+
+/// Writes a new key file, readable by its owner only.
+fn write_key_file(path: &Path, key_file: &KeyFile) -> Result<(), RwaError> {
+    let contents = serde_json::to_vec_pretty(key_file)
+        .map_err(|e| RwaError::Encoding(format!("failed to serialize key file: {}", e)))?;
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(&contents))
+        .map_err(|e| key_file_error(&key_file.name, path, e))
+}
+
+fn key_file_error(name: &str, path: &Path, error: std::io::Error) -> RwaError {
+    match error.kind() {
+        std::io::ErrorKind::NotFound => RwaError::Keystore(format!("no key named `{}`", name)),
+        std::io::ErrorKind::AlreadyExists => {
+            RwaError::Keystore(format!("a key named `{}` already exists", name))
+        }
+        _ => RwaError::Keystore(format!("cannot access {}: {}", path.display(), error)),
+    }
+}
@@ -0,0 +1,126 @@
+//! In-memory secp256k1 signer.
+
+use async_trait::async_trait;
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmrs::crypto::PublicKey;
+use sha3::{Digest, Keccak256};
+
+use super::{KeyType, SignMode, Signer};
+use crate::RwaError;
+
+/// A [`Signer`] holding a secp256k1 private key in memory.
+pub struct LocalSigner {
+    key: LocalKey,
+    sign_mode: SignMode,
+}
+
+/// The private key of a [`LocalSigner`], by key type.
+enum LocalKey {
+    Secp256k1(SigningKey),
+    EthSecp256k1(k256::ecdsa::SigningKey),
+}
+
+impl std::fmt::Debug for LocalSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the private key
+        f.debug_struct("LocalSigner")
+            .field("public_key", &self.public_key())
+            .field("key_type", &self.key_type())
+            .field("sign_mode", &self.sign_mode)
+            .finish()
+    }
+}
+
+impl LocalSigner {
+    /// Creates a signer from a signing key.
+    pub fn new(key: SigningKey) -> Self {
+        Self {
+            key: LocalKey::Secp256k1(key),
+            sign_mode: SignMode::default(),
+        }
+    }
+
+    /// Creates a signer from the raw bytes of a private key.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, RwaError> {
+        SigningKey::from_slice(bytes)
+            .map(Self::new)
+            .map_err(|e| RwaError::Signing(e.to_string()))
+    }
+
+    /// Creates a signer from the raw bytes of a private key of the given type.
+    ///
+    /// `KeyType::EthSecp256k1` signers sign for the EVM account of the key,
+    /// e.g. the MetaMask account of the same private key.
+    pub fn from_slice_with_key_type(bytes: &[u8], key_type: KeyType) -> Result<Self, RwaError> {
+        match key_type {
+            KeyType::Secp256k1 => Self::from_slice(bytes),
+            KeyType::EthSecp256k1 => {
+                let key = k256::ecdsa::SigningKey::from_slice(bytes)
+                    .map_err(|e| RwaError::Signing(e.to_string()))?;
+                Ok(Self {
+                    key: LocalKey::EthSecp256k1(key),
+                    sign_mode: SignMode::default(),
+                })
+            }
+        }
+    }
+
+    /// Creates a signer with a random private key.
+    pub fn random() -> Self {
+        Self::new(SigningKey::random())
+    }
+
+    /// Sets the sign mode of the transactions signed by this signer.
+    pub fn with_sign_mode(mut self, sign_mode: SignMode) -> Self {
+        self.sign_mode = sign_mode;
+        self
+    }
+}
+
+impl From<SigningKey> for LocalSigner {
+    fn from(key: SigningKey) -> Self {
+        Self::new(key)
+    }
+}
+
+#[async_trait]
+impl Signer for LocalSigner {
+    fn public_key(&self) -> PublicKey {
+        match &self.key {
+            LocalKey::Secp256k1(key) => key.public_key(),
+            LocalKey::EthSecp256k1(key) => key.verifying_key().into(),
+        }
+    }
+
+    fn key_type(&self) -> KeyType {
+        match self.key {
+            LocalKey::Secp256k1(_) => KeyType::Secp256k1,
+            LocalKey::EthSecp256k1(_) => KeyType::EthSecp256k1,
+        }
+    }
+
+    fn sign_mode(&self) -> SignMode {
+        self.sign_mode
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> Result<Vec<u8>, RwaError> {
+        match &self.key {
+            LocalKey::Secp256k1(key) => {
+                let signature = key
+                    .sign(sign_bytes)
+                    .map_err(|e| RwaError::Signing(e.to_string()))?;
+                Ok(signature.to_vec())
+            }
+            // `r || s || v` over the Keccak-256 of the sign bytes, as signed
+            // by Ethereum wallets
+            LocalKey::EthSecp256k1(key) => {
+                let (signature, recovery_id) = key
+                    .sign_prehash_recoverable(&Keccak256::digest(sign_bytes))
+                    .map_err(|e| RwaError::Signing(e.to_string()))?;
+                let mut bytes = signature.to_vec();
+                bytes.push(recovery_id.to_byte());
+                Ok(bytes)
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Transaction signers for the RWA SDK.
+//!
+//! [`RwaClient`](crate::RwaClient) signs transactions through the [`Signer`]
+//! trait, so private keys do not have to live in the client process.
+//! [`LocalSigner`] signs with an in-memory secp256k1 key, derived from a BIP39
+//! mnemonic through a [`Wallet`], decrypted from a [`Keystore`] or given as
+//! raw bytes, and [`RemoteSigner`]
+//! delegates to a signing service, e.g. one backed by an HSM or a KMS.
+//! Accounts controlled by several keys are described by a [`MultisigAccount`].
+//!
+//! Keys are Cosmos `secp256k1` keys, or the `eth_secp256k1` keys of EVM
+//! accounts, see [`KeyType`].
+
+use async_trait::async_trait;
+use cosmrs::crypto::secp256k1::VerifyingKey;
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::crypto::secp256k1::PubKey;
+use cosmrs::proto::prost::Message;
+use cosmrs::tx::{ModeInfo, SignMode as ProtoSignMode, SignerInfo, SignerPublicKey};
+use cosmrs::{AccountId, Any};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use crate::{RwaError, UnsignedTx};
+
+mod keystore;
+mod local;
+mod multisig;
+mod remote;
+mod wallet;
+
+pub use keystore::{KeyInfo, Keystore, DEFAULT_SCRYPT_LOG_N};
+pub use local::LocalSigner;
+pub use multisig::{MultisigAccount, PartialSignature};
+pub use remote::RemoteSigner;
+pub use wallet::{HdPath, Wallet, COSMOS_COIN_TYPE, DEFAULT_ACCOUNT_PREFIX, ETHEREUM_COIN_TYPE};
+
+/// The encoding of the transaction a signer signs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignMode {
+    /// `SIGN_MODE_DIRECT`, the protobuf encoded `SignDoc`
+    #[default]
+    Direct,
+    /// `SIGN_MODE_LEGACY_AMINO_JSON`, the canonical JSON `StdSignDoc` required
+    /// by hardware wallets and some custody providers
+    AminoJson,
+}
+
+/// Type URL of the public key of `eth_secp256k1` accounts.
+pub const ETH_SECP256K1_TYPE_URL: &str = "/ethermint.crypto.v1.ethsecp256k1.PubKey";
+
+/// The type of the key of an account, which selects how its address is
+/// derived and what its signatures cover.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyType {
+    /// A Cosmos `secp256k1` key, addressed by the RIPEMD-160 of the SHA-256
+    /// of its compressed public key and signing the SHA-256 of the sign bytes
+    #[default]
+    Secp256k1,
+    /// An `eth_secp256k1` key of an EVM account, e.g. a MetaMask account,
+    /// addressed by the last 20 bytes of the Keccak-256 of its uncompressed
+    /// public key and signing the Keccak-256 of the sign bytes
+    EthSecp256k1,
+}
+
+impl KeyType {
+    /// Returns the account address of a public key of this type.
+    pub fn account_id(self, public_key: &PublicKey, prefix: &str) -> Result<AccountId, RwaError> {
+        match self {
+            KeyType::Secp256k1 => public_key
+                .account_id(prefix)
+                .map_err(|e| RwaError::Signing(e.to_string())),
+            KeyType::EthSecp256k1 => {
+                let key = VerifyingKey::from_sec1_bytes(&public_key.to_bytes())
+                    .map_err(|e| RwaError::Signing(format!("invalid eth_secp256k1 key: {}", e)))?;
+                let uncompressed = key.to_encoded_point(false);
+                let hash = Keccak256::digest(&uncompressed.as_bytes()[1..]);
+                AccountId::new(prefix, &hash[12..]).map_err(|e| RwaError::Signing(e.to_string()))
+            }
+        }
+    }
+
+    /// Returns the public key declared in the signer info of a transaction.
+    pub(crate) fn signer_public_key(self, public_key: PublicKey) -> SignerPublicKey {
+        match self {
+            KeyType::Secp256k1 => public_key.into(),
+            KeyType::EthSecp256k1 => SignerPublicKey::Any(Any {
+                type_url: ETH_SECP256K1_TYPE_URL.to_string(),
+                value: PubKey {
+                    key: public_key.to_bytes(),
+                }
+                .encode_to_vec(),
+            }),
+        }
+    }
+}
+
+/// A key able to sign transactions.
+#[async_trait]
+pub trait Signer: std::fmt::Debug + Send + Sync {
+    /// Returns the public key of the signer.
+    fn public_key(&self) -> PublicKey;
+
+    /// Returns the account address of the signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The bech32 prefix of the chain, e.g. `kii`
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the AccountId or an error
+    fn account_id(&self, prefix: &str) -> Result<AccountId, RwaError> {
+        self.key_type().account_id(&self.public_key(), prefix)
+    }
+
+    /// Returns the type of the key of the signer.
+    fn key_type(&self) -> KeyType {
+        KeyType::Secp256k1
+    }
+
+    /// Returns the sign mode of the transactions signed by this signer.
+    fn sign_mode(&self) -> SignMode {
+        SignMode::Direct
+    }
+
+    /// Signs the sign bytes of a transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_bytes` - The serialized sign document, protobuf or Amino JSON
+    ///   depending on the sign mode
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the signature, in the encoding expected by the
+    /// chain for the signer's key type, or an error
+    async fn sign(&self, sign_bytes: &[u8]) -> Result<Vec<u8>, RwaError>;
+}
+
+/// The key declared as the signer of a transaction.
+#[derive(Debug, Clone)]
+pub(crate) enum TxKey {
+    Single {
+        public_key: PublicKey,
+        key_type: KeyType,
+        sign_mode: SignMode,
+    },
+    Multisig(MultisigAccount),
+}
+
+impl TxKey {
+    /// Returns the address of the account signing with the key.
+    pub(crate) fn account_id(&self, prefix: &str) -> Result<AccountId, RwaError> {
+        match self {
+            TxKey::Single {
+                public_key,
+                key_type,
+                ..
+            } => key_type.account_id(public_key, prefix),
+            TxKey::Multisig(multisig) => multisig.account_id(prefix),
+        }
+    }
+
+    /// Returns the signer info of a transaction to be signed.
+    ///
+    /// The members signing for a multisig are unknown until their partial
+    /// signatures are combined, so none is marked yet.
+    pub(crate) fn signer_info(&self, sequence: u64) -> SignerInfo {
+        match self {
+            TxKey::Single {
+                public_key,
+                key_type,
+                sign_mode,
+            } => {
+                let mode = match sign_mode {
+                    SignMode::Direct => ProtoSignMode::Direct,
+                    SignMode::AminoJson => ProtoSignMode::LegacyAminoJson,
+                };
+                SignerInfo {
+                    public_key: Some(key_type.signer_public_key(*public_key)),
+                    mode_info: ModeInfo::single(mode),
+                    sequence,
+                }
+            }
+            TxKey::Multisig(multisig) => {
+                multisig.signer_info(&vec![false; multisig.public_keys().len()], sequence)
+            }
+        }
+    }
+
+    /// Returns the signer info and the placeholder signature of a simulated transaction.
+    ///
+    /// A multisig is simulated as signed by its first `threshold` members, so
+    /// the gas of their signature checks is accounted for.
+    pub(crate) fn simulation_signer(&self, sequence: u64) -> (SignerInfo, Vec<u8>) {
+        match self {
+            TxKey::Single { .. } => (self.signer_info(sequence), Vec::new()),
+            TxKey::Multisig(multisig) => {
+                let threshold = multisig.threshold() as usize;
+                let signed: Vec<bool> = (0..multisig.public_keys().len())
+                    .map(|index| index < threshold)
+                    .collect();
+                (
+                    multisig.signer_info(&signed, sequence),
+                    MultisigAccount::multi_signature(vec![Vec::new(); threshold]),
+                )
+            }
+        }
+    }
+}
+
+/// The signers of a transaction signed and broadcast in one call.
+pub(crate) enum TxSigners<'a> {
+    Single(&'a dyn Signer, SignMode),
+    Multisig(&'a MultisigAccount, &'a [&'a dyn Signer]),
+}
+
+impl TxSigners<'_> {
+    /// Returns the key declared as the signer of the transaction.
+    pub(crate) fn key(&self) -> TxKey {
+        match self {
+            TxSigners::Single(signer, sign_mode) => TxKey::Single {
+                public_key: signer.public_key(),
+                key_type: signer.key_type(),
+                sign_mode: *sign_mode,
+            },
+            TxSigners::Multisig(multisig, _) => TxKey::Multisig((*multisig).clone()),
+        }
+    }
+
+    /// Signs a transaction built for [`TxSigners::key`] into an encoded `TxRaw`.
+    pub(crate) async fn sign(&self, unsigned_tx: &UnsignedTx) -> Result<Vec<u8>, RwaError> {
+        match self {
+            TxSigners::Single(signer, _) => unsigned_tx.sign(*signer).await,
+            TxSigners::Multisig(_, signers) => {
+                let mut signatures = Vec::with_capacity(signers.len());
+                for signer in signers.iter() {
+                    signatures.push(unsigned_tx.sign_partial(*signer).await?);
+                }
+                unsigned_tx.combine(&signatures)
+            }
+        }
+    }
+}
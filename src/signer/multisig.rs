@@ -0,0 +1,157 @@
+//! k-of-n multisig accounts.
+
+use cosmrs::crypto::{LegacyAminoMultisig, PublicKey};
+use cosmrs::proto::cosmos::crypto::multisig::v1beta1::{CompactBitArray, MultiSignature};
+use cosmrs::proto::cosmos::tx::v1beta1::mode_info::{Multi, Single, Sum};
+use cosmrs::proto::cosmos::tx::v1beta1::ModeInfo as ProtoModeInfo;
+use cosmrs::proto::prost::Message;
+use cosmrs::tx::{ModeInfo, SignMode, SignerInfo};
+use cosmrs::AccountId;
+use cosmwasm_std::Binary;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::RwaError;
+
+/// Amino prefix of a `LegacyAminoPubKey`.
+const MULTISIG_AMINO_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+
+/// Amino prefix of a secp256k1 public key.
+const SECP256K1_AMINO_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+
+/// A k-of-n multisig account, a `LegacyAminoPubKey`.
+///
+/// The public keys must be given in the order of the on-chain account, since
+/// the order is part of the account address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigAccount {
+    key: LegacyAminoMultisig,
+}
+
+/// A signature of one member of a multisig account, exchanged as a file
+/// until enough members signed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialSignature {
+    /// The compressed secp256k1 public key of the member
+    pub public_key: Binary,
+    /// The signature of the member over the Amino JSON sign document
+    pub signature: Binary,
+}
+
+impl MultisigAccount {
+    /// Creates a multisig account.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The number of signatures required, `k`
+    /// * `public_keys` - The public keys of the members, `n`, in account order
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the MultisigAccount, or an error if the threshold
+    /// cannot be met
+    pub fn new(threshold: u32, public_keys: Vec<PublicKey>) -> Result<Self, RwaError> {
+        if threshold == 0 || threshold as usize > public_keys.len() {
+            return Err(RwaError::Config(format!(
+                "invalid multisig threshold {} for {} public keys",
+                threshold,
+                public_keys.len()
+            )));
+        }
+
+        Ok(Self {
+            key: LegacyAminoMultisig {
+                threshold,
+                public_keys,
+            },
+        })
+    }
+
+    /// Returns the number of signatures required.
+    pub fn threshold(&self) -> u32 {
+        self.key.threshold
+    }
+
+    /// Returns the public keys of the members.
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.key.public_keys
+    }
+
+    /// Returns the account address of the multisig.
+    pub fn account_id(&self, prefix: &str) -> Result<AccountId, RwaError> {
+        let hash = Sha256::digest(self.amino_bytes());
+        AccountId::new(prefix, &hash[..20]).map_err(|e| RwaError::Signing(e.to_string()))
+    }
+
+    /// Wraps the multisig key declared in a transaction.
+    pub(crate) fn from_key(key: LegacyAminoMultisig) -> Self {
+        Self { key }
+    }
+
+    /// Builds the signer info of a multisig transaction signed by the marked members.
+    ///
+    /// Members sign the Amino JSON sign document, which does not cover the
+    /// signer info, so it can be completed once their signatures are collected.
+    pub(crate) fn signer_info(&self, signed: &[bool], sequence: u64) -> SignerInfo {
+        let mut elems = vec![0u8; signed.len().div_ceil(8)];
+        for (index, _) in signed.iter().enumerate().filter(|(_, signed)| **signed) {
+            elems[index / 8] |= 1 << (7 - index % 8);
+        }
+        let mode_infos = signed
+            .iter()
+            .filter(|signed| **signed)
+            .map(|_| ProtoModeInfo {
+                sum: Some(Sum::Single(Single {
+                    mode: SignMode::LegacyAminoJson.into(),
+                })),
+            })
+            .collect();
+        let mode_info = ProtoModeInfo {
+            sum: Some(Sum::Multi(Multi {
+                bitarray: Some(CompactBitArray {
+                    extra_bits_stored: (signed.len() % 8) as u32,
+                    elems,
+                }),
+                mode_infos,
+            })),
+        };
+
+        SignerInfo {
+            public_key: Some(self.key.clone().into()),
+            mode_info: ModeInfo::try_from(mode_info).expect("multisig mode info is well formed"),
+            sequence,
+        }
+    }
+
+    /// Encodes the signatures of the signing members, in member order.
+    pub(crate) fn multi_signature(signatures: Vec<Vec<u8>>) -> Vec<u8> {
+        MultiSignature { signatures }.encode_to_vec()
+    }
+
+    /// Encodes the key in the Amino binary format the address is derived from.
+    fn amino_bytes(&self) -> Vec<u8> {
+        let mut bytes = MULTISIG_AMINO_PREFIX.to_vec();
+        bytes.push(0x08);
+        encode_uvarint(self.key.threshold.into(), &mut bytes);
+
+        for public_key in &self.key.public_keys {
+            let key_bytes = public_key.to_bytes();
+            let mut member = SECP256K1_AMINO_PREFIX.to_vec();
+            encode_uvarint(key_bytes.len() as u64, &mut member);
+            member.extend_from_slice(&key_bytes);
+
+            bytes.push(0x12);
+            encode_uvarint(member.len() as u64, &mut bytes);
+            bytes.extend_from_slice(&member);
+        }
+        bytes
+    }
+}
+
+fn encode_uvarint(mut value: u64, bytes: &mut Vec<u8>) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
@@ -0,0 +1,169 @@
+//! Signer delegating to a remote signing service.
+//!
+//! The service listens on a TCP socket, usually on localhost, and speaks
+//! newline-delimited JSON. Every request opens a connection, writes one
+//! request line and reads one response line. Binary values are base64 encoded.
+//!
+//! ```text
+//! -> {"method":"public_key","key_id":"issuer"}
+//! <- {"public_key":"A+3Y..."}
+//! -> {"method":"sign","key_id":"issuer","sign_bytes":"CpMBCpAB..."}
+//! <- {"signature":"kq0Z..."}
+//! ```
+//!
+//! The public key is the 33 byte compressed secp256k1 key, and the signature
+//! the 64 byte `r || s` encoding over the SHA-256 digest of the sign bytes.
+//! The sign bytes are the protobuf `SignDoc` or the Amino JSON `StdSignDoc`,
+//! depending on the sign mode of the signer.
+//! A failed request is answered with `{"error":"<reason>"}`.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cosmrs::crypto::secp256k1::VerifyingKey;
+use cosmrs::crypto::PublicKey;
+use cosmwasm_std::Binary;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+use super::{SignMode, Signer};
+use crate::RwaError;
+
+/// Default time allowed for one request to the signing service.
+pub const DEFAULT_REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A [`Signer`] delegating to a signing service over a local socket.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    address: String,
+    key_id: String,
+    public_key: PublicKey,
+    timeout: Duration,
+    sign_mode: SignMode,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum Request<'a> {
+    PublicKey { key_id: &'a str },
+    Sign { key_id: &'a str, sign_bytes: Binary },
+}
+
+#[derive(Deserialize)]
+struct Response {
+    public_key: Option<Binary>,
+    signature: Option<Binary>,
+    error: Option<String>,
+}
+
+impl RemoteSigner {
+    /// Connects to a signing service and fetches the public key of a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The socket address of the service, e.g. `127.0.0.1:7070`
+    /// * `key_id` - The identifier of the key within the service
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the RemoteSigner, or an error if the service is
+    /// unreachable or does not know the key
+    pub async fn connect(address: &str, key_id: &str) -> Result<Self, RwaError> {
+        Self::connect_with_timeout(address, key_id, DEFAULT_REMOTE_SIGNER_TIMEOUT).await
+    }
+
+    /// Same as [`RemoteSigner::connect`], with a custom request timeout.
+    pub async fn connect_with_timeout(
+        address: &str,
+        key_id: &str,
+        timeout: Duration,
+    ) -> Result<Self, RwaError> {
+        let response = request(address, timeout, &Request::PublicKey { key_id }).await?;
+        let public_key = response.public_key.ok_or_else(|| {
+            RwaError::Signing("signing service returned no public key".to_string())
+        })?;
+        let public_key = VerifyingKey::from_sec1_bytes(public_key.as_slice())
+            .map_err(|e| RwaError::Signing(format!("invalid public key: {}", e)))?;
+
+        Ok(Self {
+            address: address.to_string(),
+            key_id: key_id.to_string(),
+            public_key: public_key.into(),
+            timeout,
+            sign_mode: SignMode::default(),
+        })
+    }
+
+    /// Sets the sign mode of the transactions sent to the signing service.
+    pub fn with_sign_mode(mut self, sign_mode: SignMode) -> Self {
+        self.sign_mode = sign_mode;
+        self
+    }
+
+    /// Returns the identifier of the key within the signing service.
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn sign_mode(&self) -> SignMode {
+        self.sign_mode
+    }
+
+    async fn sign(&self, sign_bytes: &[u8]) -> Result<Vec<u8>, RwaError> {
+        let sign = Request::Sign {
+            key_id: &self.key_id,
+            sign_bytes: Binary::from(sign_bytes),
+        };
+        let response = request(&self.address, self.timeout, &sign).await?;
+        let signature = response.signature.ok_or_else(|| {
+            RwaError::Signing("signing service returned no signature".to_string())
+        })?;
+        Ok(signature.to_vec())
+    }
+}
+
+/// Sends one request to the signing service and reads its response.
+async fn request(
+    address: &str,
+    timeout: Duration,
+    request: &Request<'_>,
+) -> Result<Response, RwaError> {
+    let exchange = async {
+        let mut line = serde_json::to_vec(request).map_err(|e| e.to_string())?;
+        line.push(b'\n');
+
+        let mut stream = TcpStream::connect(address)
+            .await
+            .map_err(|e| e.to_string())?;
+        stream.write_all(&line).await.map_err(|e| e.to_string())?;
+
+        let mut response = String::new();
+        BufReader::new(stream)
+            .read_line(&mut response)
+            .await
+            .map_err(|e| e.to_string())?;
+        serde_json::from_str::<Response>(&response).map_err(|e| e.to_string())
+    };
+
+    let response = tokio::time::timeout(timeout, exchange)
+        .await
+        .map_err(|_| format!("no response within {:?}", timeout))
+        .and_then(|response| response)
+        .map_err(|e| RwaError::Signing(format!("signing service {}: {}", address, e)))?;
+
+    match response.error {
+        Some(error) => Err(RwaError::Signing(format!(
+            "signing service {} refused the request: {}",
+            address, error
+        ))),
+        None => Ok(response),
+    }
+}
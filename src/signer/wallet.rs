@@ -0,0 +1,219 @@
+//! BIP39 mnemonic wallets with BIP44 hierarchical key derivation.
+
+use std::fmt;
+use std::str::FromStr;
+
+use bip32::{DerivationPath, Seed, XPrv};
+use bip39::{Language, Mnemonic};
+use cosmrs::AccountId;
+use zeroize::Zeroizing;
+
+use super::{KeyType, LocalSigner, Signer};
+use crate::RwaError;
+
+/// BIP44 coin type of Cosmos SDK chains, used by the accounts of a [`Wallet`]
+/// unless configured otherwise.
+pub const COSMOS_COIN_TYPE: u32 = 118;
+
+/// BIP44 coin type of Ethereum.
+///
+/// KiiChain accounts of this coin type, e.g. MetaMask accounts, are
+/// `eth_secp256k1` accounts addressed by the Keccak hash of their key.
+pub const ETHEREUM_COIN_TYPE: u32 = 60;
+
+/// Bech32 prefix of KiiChain account addresses.
+pub const DEFAULT_ACCOUNT_PREFIX: &str = "kii";
+
+/// A BIP44 derivation path, `m/44'/{coin_type}'/{account}'/{change}/{index}`.
+///
+/// Keys derived on the Ethereum coin type are `eth_secp256k1` keys, signing
+/// for the EVM account of the same mnemonic; keys of any other coin type are
+/// Cosmos `secp256k1` keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HdPath {
+    /// The registered coin type, e.g. `118` for Cosmos or `60` for Ethereum
+    pub coin_type: u32,
+    /// The hardened account level
+    pub account: u32,
+    /// `0` for external addresses, `1` for change addresses
+    pub change: u32,
+    /// The index of the address
+    pub index: u32,
+}
+
+impl HdPath {
+    /// Creates the path of the address at `index` of the first account.
+    pub fn new(coin_type: u32, index: u32) -> Self {
+        Self {
+            coin_type,
+            account: 0,
+            change: 0,
+            index,
+        }
+    }
+
+    /// Creates the Cosmos path `m/44'/118'/0'/0/{index}`.
+    pub fn cosmos(index: u32) -> Self {
+        Self::new(COSMOS_COIN_TYPE, index)
+    }
+
+    /// Creates the Ethereum path `m/44'/60'/0'/0/{index}`.
+    pub fn ethereum(index: u32) -> Self {
+        Self::new(ETHEREUM_COIN_TYPE, index)
+    }
+
+    /// Returns the type of the keys derived on this path.
+    pub fn key_type(&self) -> KeyType {
+        if self.coin_type == ETHEREUM_COIN_TYPE {
+            KeyType::EthSecp256k1
+        } else {
+            KeyType::Secp256k1
+        }
+    }
+}
+
+impl fmt::Display for HdPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m/44'/{}'/{}'/{}/{}",
+            self.coin_type, self.account, self.change, self.index
+        )
+    }
+}
+
+impl FromStr for HdPath {
+    type Err = RwaError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            RwaError::Config(format!(
+                "invalid derivation path `{}`, expected m/44'/<coin type>'/<account>'/<change>/<index>",
+                path
+            ))
+        };
+        let hardened = |level: &str| level.strip_suffix('\'')?.parse::<u32>().ok();
+        let normal = |level: &str| level.parse::<u32>().ok();
+
+        let levels: Vec<&str> = path.split('/').collect();
+        let [master, purpose, coin_type, account, change, index] = levels.as_slice() else {
+            return Err(invalid());
+        };
+        if *master != "m" || hardened(purpose) != Some(44) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            coin_type: hardened(coin_type).ok_or_else(invalid)?,
+            account: hardened(account).ok_or_else(invalid)?,
+            change: normal(change).ok_or_else(invalid)?,
+            index: normal(index).ok_or_else(invalid)?,
+        })
+    }
+}
+
+/// A hierarchical deterministic wallet backed by a BIP39 mnemonic.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use erc3643sdk::{HdPath, Wallet};
+///
+/// # fn main() -> Result<(), erc3643sdk::RwaError> {
+/// let wallet = Wallet::from_mnemonic("abandon abandon ... about", "")?.with_prefix("kii");
+/// let issuer = wallet.account(0)?;
+/// let agent = wallet.derive(&HdPath::cosmos(1))?;
+/// println!("issuer: {}", wallet.address(0)?);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Wallet {
+    seed: Seed,
+    coin_type: u32,
+    prefix: String,
+}
+
+impl fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Never print the seed
+        f.debug_struct("Wallet")
+            .field("coin_type", &self.coin_type)
+            .field("prefix", &self.prefix)
+            .finish()
+    }
+}
+
+impl Wallet {
+    /// Restores a wallet from an English BIP39 mnemonic of 12 to 24 words.
+    ///
+    /// Accounts are derived on the Cosmos coin type and addressed with the
+    /// `kii` prefix unless configured otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `phrase` - The mnemonic phrase
+    /// * `passphrase` - The optional BIP39 passphrase, empty if none
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the Wallet, or an error if the mnemonic is invalid
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self, RwaError> {
+        let mnemonic = Mnemonic::parse_in(Language::English, phrase)
+            .map_err(|e| RwaError::Signing(format!("invalid mnemonic: {}", e)))?;
+
+        Ok(Self {
+            seed: Seed::new(mnemonic.to_seed(passphrase)),
+            coin_type: COSMOS_COIN_TYPE,
+            prefix: DEFAULT_ACCOUNT_PREFIX.to_string(),
+        })
+    }
+
+    /// Sets the coin type used by [`Wallet::account`] and [`Wallet::address`].
+    ///
+    /// Accounts of [`ETHEREUM_COIN_TYPE`] are `eth_secp256k1` accounts, the
+    /// EVM accounts of the mnemonic.
+    pub fn with_coin_type(mut self, coin_type: u32) -> Self {
+        self.coin_type = coin_type;
+        self
+    }
+
+    /// Sets the bech32 prefix of the account addresses.
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Returns the bech32 prefix of the account addresses.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Derives the key at an arbitrary BIP44 path, of the path's key type.
+    pub fn derive(&self, path: &HdPath) -> Result<LocalSigner, RwaError> {
+        LocalSigner::from_slice_with_key_type(&self.derive_secret(path)?, path.key_type())
+    }
+
+    /// Derives the raw private key at a BIP44 path.
+    pub(crate) fn derive_secret(&self, path: &HdPath) -> Result<Zeroizing<Vec<u8>>, RwaError> {
+        let derivation_path = DerivationPath::from_str(&path.to_string())
+            .map_err(|e| RwaError::Signing(format!("invalid derivation path {}: {}", path, e)))?;
+        let key = XPrv::derive_from_path(self.seed.as_bytes(), &derivation_path)
+            .map_err(|e| RwaError::Signing(format!("key derivation at {} failed: {}", path, e)))?;
+        Ok(Zeroizing::new(key.private_key().to_bytes().to_vec()))
+    }
+
+    /// Derives the key of the account at `index`, `m/44'/{coin_type}'/0'/0/{index}`.
+    pub fn account(&self, index: u32) -> Result<LocalSigner, RwaError> {
+        self.derive(&HdPath::new(self.coin_type, index))
+    }
+
+    /// Derives the keys of the first `count` accounts.
+    pub fn accounts(&self, count: u32) -> Result<Vec<LocalSigner>, RwaError> {
+        (0..count).map(|index| self.account(index)).collect()
+    }
+
+    /// Returns the address of the account at `index`.
+    pub fn address(&self, index: u32) -> Result<AccountId, RwaError> {
+        self.account(index)?.account_id(&self.prefix)
+    }
+}
@@ -4,13 +4,41 @@
 //! and other token-related operations.
 
 pub mod request;
-use request::{TokenInfoRequest, TransferMessageRequest};
 
-use crate::{ExecuteResponse, RwaClient};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cosmrs::AccountId;
+use cosmwasm_std::Uint128;
+pub use cw20::Expiration;
+use request::{
+    AllAllowancesRequest, AllSpenderAllowancesRequest, AllowanceChangeRequest, AllowanceRequest,
+    BatchPartialFreezeRequest, BatchSetAddressFrozenRequest, BurnFromRequest, BurnRequest,
+    MintRequest, PartialFreezeRequest, PauseRequest, SetAddressFrozenRequest, TokenInfoRequest,
+    TransferFromRequest, TransferMessageRequest, UpdateMinterRequest,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{Address, ExecuteResponse, RwaClient, RwaError, TxBatch};
+
+/// The token balance of an account, split between the tokens it may
+/// transfer and those frozen by an agent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBalance {
+    /// All tokens held by the account
+    pub balance: Uint128,
+    /// The frozen tokens, the whole balance if the address itself is frozen
+    pub frozen: Uint128,
+    /// The tokens the account may transfer
+    pub free: Uint128,
+}
 
 impl RwaClient {
     /// Transfers tokens from the sender to a recipient.
     ///
+    /// Fails with `RwaError::TokenPaused` without broadcasting while the
+    /// token is paused.
+    ///
     /// # Arguments
     ///
     /// * `request` - A TransferMessageRequest containing transfer details
@@ -22,9 +50,10 @@ impl RwaClient {
     pub async fn transfer(
         &self,
         request: TransferMessageRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ExecuteResponse, RwaError> {
+        self.ensure_not_paused().await?;
         let msg = cw20::Cw20ExecuteMsg::Transfer {
-            recipient: request.to.clone(),
+            recipient: self.account_id(&request.to)?.to_string(),
             amount: request.amount.into(),
         };
 
@@ -33,54 +62,124 @@ impl RwaClient {
             &msg,
             self.token_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
     }
 
-    /// Transfers tokens from one address to another, given prior approval.
+    /// Transfers tokens of an owner to a recipient, spending the allowance
+    /// the owner gave the spender.
+    ///
+    /// The allowance and the paused state of the token are checked before
+    /// broadcasting, so a transfer the token contract would reject does not
+    /// cost any fee.
     ///
     /// # Arguments
     ///
-    /// * `request` - A TransferMessageRequest containing transfer details
+    /// * `request` - A `TransferFromRequest` containing:
+    ///   - `spender`: The account spending the allowance, which signs the transaction
+    ///   - `owner`: The account owning the tokens
+    ///   - `recipient`: The account receiving the tokens
+    ///   - `amount`: The amount of tokens to transfer
     ///
     /// # Returns
     ///
     /// A `ExecuteResponse` containing information about the transaction if successful,
-    /// or an error if the operation fails.
+    /// `RwaError::TokenPaused` while the token is paused,
+    /// `RwaError::InsufficientAllowance` or `RwaError::AllowanceExpired` if the
+    /// allowance does not cover the transfer, or another error if the operation fails.
     pub async fn transfer_from(
         &self,
-        request: TransferMessageRequest,
-    ) -> Result<ExecuteResponse, Box<dyn std::error::Error>> {
+        request: TransferFromRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let owner = self.account_id(&request.owner)?;
+        let spender = self.account_id(&request.spender)?;
+        self.ensure_not_paused().await?;
+        self.check_allowance(&owner, &spender, request.amount)
+            .await?;
+
         let msg = cw20::Cw20ExecuteMsg::TransferFrom {
-            owner: request.from.clone(),
-            recipient: request.to.clone(),
+            owner: owner.to_string(),
+            recipient: self.account_id(&request.recipient)?.to_string(),
             amount: request.amount.into(),
         };
 
         self.execute(
-            &request.from,
+            &request.spender,
             &msg,
             self.token_address.clone(),
             vec![],
-            &request.signer,
+            request.signer.as_ref(),
             request.gas_limit,
         )
         .await
     }
 
+    /// Checks that the allowance of `spender` over the tokens of `owner`
+    /// covers `amount` and is still valid in the next block.
+    ///
+    /// An allowance expiring at a time is compared with the local clock, as
+    /// the time of the next block is not known yet.
+    async fn check_allowance(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+        amount: u128,
+    ) -> Result<(), RwaError> {
+        let msg = cw20::Cw20QueryMsg::Allowance {
+            owner: owner.to_string(),
+            spender: spender.to_string(),
+        };
+        let (response, height): (cw20::AllowanceResponse, u64) = self
+            .query_at_height(&self.token_address, &msg, None)
+            .await?;
+
+        let expired = match response.expires {
+            Expiration::AtHeight(expiry) => height + 1 >= expiry,
+            Expiration::AtTime(expiry) => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default();
+                now.as_nanos() >= u128::from(expiry.nanos())
+            }
+            Expiration::Never {} => false,
+        };
+        if expired {
+            return Err(RwaError::AllowanceExpired {
+                owner: owner.to_string(),
+                spender: spender.to_string(),
+            });
+        }
+
+        let allowance = response.allowance.u128();
+        if allowance < amount {
+            return Err(RwaError::InsufficientAllowance {
+                owner: owner.to_string(),
+                spender: spender.to_string(),
+                allowance,
+                required: amount,
+            });
+        }
+        Ok(())
+    }
+
     /// Retrieves information about the token.
     ///
     /// # Returns
     ///
     /// A Result containing a TokenInfoResponse or an error
-    pub async fn coin_info(&self) -> Result<cw20::TokenInfoResponse, Box<dyn std::error::Error>> {
+    pub async fn coin_info(&self) -> Result<cw20::TokenInfoResponse, RwaError> {
         let msg = cw20::Cw20QueryMsg::TokenInfo {};
         self.query(&self.token_address, &msg).await
     }
 
-    /// Retrieves the token balance of a given address.
+    /// Retrieves the token balance of a given address, split between the
+    /// tokens it may transfer and those frozen by an agent.
+    ///
+    /// The balance and the frozen tokens are queried at the same height. A
+    /// token contract that does not support freezes, e.g. a plain cw20
+    /// token, reports no frozen tokens.
     ///
     /// # Arguments
     ///
@@ -88,14 +187,919 @@ impl RwaClient {
     ///
     /// # Returns
     ///
-    /// A Result containing a BalanceResponse or an error
-    pub async fn balance(
-        &self,
-        request: TokenInfoRequest,
-    ) -> Result<cw20::BalanceResponse, Box<dyn std::error::Error>> {
+    /// A Result containing a TokenBalance or an error
+    pub async fn balance(&self, request: TokenInfoRequest) -> Result<TokenBalance, RwaError> {
+        let address = self.account_id(&request.address)?.to_string();
         let msg = cw20::Cw20QueryMsg::Balance {
-            address: request.address,
+            address: address.clone(),
+        };
+        let (balance, height): (cw20::BalanceResponse, u64) = self
+            .query_at_height(&self.token_address, &msg, None)
+            .await?;
+
+        let msg = QueryMsg::IsFrozen {
+            address: address.clone(),
+        };
+        let address_frozen = self.optional_token_query(&msg, Some(height)).await?;
+        let frozen = if address_frozen.unwrap_or(false) {
+            balance.balance
+        } else {
+            let msg = QueryMsg::FrozenTokens { address };
+            let frozen_tokens: Option<Uint128> =
+                self.optional_token_query(&msg, Some(height)).await?;
+            frozen_tokens.unwrap_or_default().min(balance.balance)
+        };
+
+        Ok(TokenBalance {
+            balance: balance.balance,
+            frozen,
+            free: balance.balance - frozen,
+        })
+    }
+
+    /// Retrieves whether an agent froze a whole address, which then can
+    /// neither send nor receive tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A TokenInfoRequest containing the address to query
+    ///
+    /// # Returns
+    ///
+    /// A Result containing whether the address is frozen or an error
+    pub async fn is_frozen(&self, request: TokenInfoRequest) -> Result<bool, RwaError> {
+        let msg = QueryMsg::IsFrozen {
+            address: self.account_id(&request.address)?.to_string(),
+        };
+        self.query(&self.token_address, &msg).await
+    }
+
+    /// Retrieves the amount of tokens of an address an agent froze.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A TokenInfoRequest containing the address to query
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the frozen amount or an error
+    pub async fn frozen_tokens(&self, request: TokenInfoRequest) -> Result<u128, RwaError> {
+        let msg = QueryMsg::FrozenTokens {
+            address: self.account_id(&request.address)?.to_string(),
+        };
+        let frozen: Uint128 = self.query(&self.token_address, &msg).await?;
+        Ok(frozen.u128())
+    }
+
+    /// Freezes or unfreezes a whole address. Only an agent of the token may
+    /// freeze addresses.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `SetAddressFrozenRequest` containing:
+    ///   - `from`: The agent
+    ///   - `address`: The address to freeze or unfreeze
+    ///   - `freeze`: Whether to freeze the address
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn set_address_frozen(
+        &self,
+        request: SetAddressFrozenRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = ExecuteMsg::SetAddressFrozen {
+            address: self.account_id(&request.address)?.to_string(),
+            freeze: request.freeze,
+        };
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Freezes or unfreezes the addresses of a list in one contract call.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `BatchSetAddressFrozenRequest` containing the agent and
+    ///   each address with whether to freeze it
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn batch_set_address_frozen(
+        &self,
+        request: BatchSetAddressFrozenRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let (addresses, freeze) = self.unzip_accounts(request.addresses)?;
+        let msg = ExecuteMsg::BatchSetAddressFrozen { addresses, freeze };
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Freezes part of the tokens of an address, which can no longer be
+    /// transferred. Only an agent of the token may freeze tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `PartialFreezeRequest` containing:
+    ///   - `from`: The agent
+    ///   - `address`: The address holding the tokens
+    ///   - `amount`: The amount of tokens to freeze, at most the free balance
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn freeze_partial_tokens(
+        &self,
+        request: PartialFreezeRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = ExecuteMsg::FreezePartialTokens {
+            address: self.account_id(&request.address)?.to_string(),
+            amount: request.amount.into(),
+        };
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Unfreezes part of the frozen tokens of an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `PartialFreezeRequest` containing:
+    ///   - `from`: The agent
+    ///   - `address`: The address holding the tokens
+    ///   - `amount`: The amount of tokens to unfreeze, at most the frozen amount
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn unfreeze_partial_tokens(
+        &self,
+        request: PartialFreezeRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = ExecuteMsg::UnfreezePartialTokens {
+            address: self.account_id(&request.address)?.to_string(),
+            amount: request.amount.into(),
+        };
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Freezes part of the tokens of several addresses in one contract call.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `BatchPartialFreezeRequest` containing the agent and
+    ///   each address with the amount of its tokens to freeze
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn batch_freeze_partial_tokens(
+        &self,
+        request: BatchPartialFreezeRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let (addresses, amounts) = self.unzip_accounts(request.amounts)?;
+        let msg = ExecuteMsg::BatchFreezePartialTokens {
+            addresses,
+            amounts: amounts.into_iter().map(Uint128::from).collect(),
+        };
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Unfreezes part of the frozen tokens of several addresses in one
+    /// contract call.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `BatchPartialFreezeRequest` containing the agent and
+    ///   each address with the amount of its tokens to unfreeze
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn batch_unfreeze_partial_tokens(
+        &self,
+        request: BatchPartialFreezeRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let (addresses, amounts) = self.unzip_accounts(request.amounts)?;
+        let msg = ExecuteMsg::BatchUnfreezePartialTokens {
+            addresses,
+            amounts: amounts.into_iter().map(Uint128::from).collect(),
+        };
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Splits address and value pairs into the bech32 addresses and the values.
+    fn unzip_accounts<T>(
+        &self,
+        pairs: Vec<(Address, T)>,
+    ) -> Result<(Vec<String>, Vec<T>), RwaError> {
+        let mut addresses = Vec::with_capacity(pairs.len());
+        let mut values = Vec::with_capacity(pairs.len());
+        for (address, value) in pairs {
+            addresses.push(self.account_id(&address)?.to_string());
+            values.push(value);
+        }
+        Ok((addresses, values))
+    }
+
+    /// Increases the amount of tokens a spender may transfer from the `from` account.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - An `AllowanceChangeRequest` containing:
+    ///   - `from`: The owner of the tokens
+    ///   - `spender`: The account allowed to spend them
+    ///   - `amount`: The amount added to the allowance
+    ///   - `expires`: When the allowance expires, unchanged if `None`
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn increase_allowance(
+        &self,
+        request: AllowanceChangeRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = cw20::Cw20ExecuteMsg::IncreaseAllowance {
+            spender: self.account_id(&request.spender)?.to_string(),
+            amount: request.amount.into(),
+            expires: request.expires,
+        };
+
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Decreases the amount of tokens a spender may transfer from the `from`
+    /// account. An allowance decreased below zero is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - An `AllowanceChangeRequest` containing:
+    ///   - `from`: The owner of the tokens
+    ///   - `spender`: The account allowed to spend them
+    ///   - `amount`: The amount removed from the allowance
+    ///   - `expires`: When the allowance expires, unchanged if `None`
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn decrease_allowance(
+        &self,
+        request: AllowanceChangeRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = cw20::Cw20ExecuteMsg::DecreaseAllowance {
+            spender: self.account_id(&request.spender)?.to_string(),
+            amount: request.amount.into(),
+            expires: request.expires,
+        };
+
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Retrieves the allowance of a spender over the tokens of an owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - An AllowanceRequest containing the owner and the spender
+    ///
+    /// # Returns
+    ///
+    /// A Result containing an AllowanceResponse, with a zero allowance if none
+    /// was given, or an error
+    pub async fn allowance(
+        &self,
+        request: AllowanceRequest,
+    ) -> Result<cw20::AllowanceResponse, RwaError> {
+        let msg = cw20::Cw20QueryMsg::Allowance {
+            owner: self.account_id(&request.owner)?.to_string(),
+            spender: self.account_id(&request.spender)?.to_string(),
+        };
+        self.query(&self.token_address, &msg).await
+    }
+
+    /// Lists the allowances given by an owner, ordered by spender.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - An AllAllowancesRequest containing the owner and the page
+    ///   to return: the spender to start after and the page size, limited by
+    ///   the contract
+    ///
+    /// # Returns
+    ///
+    /// A Result containing an AllAllowancesResponse or an error
+    pub async fn all_allowances(
+        &self,
+        request: AllAllowancesRequest,
+    ) -> Result<cw20::AllAllowancesResponse, RwaError> {
+        let msg = cw20::Cw20QueryMsg::AllAllowances {
+            owner: self.account_id(&request.owner)?.to_string(),
+            start_after: self.optional_account(request.start_after.as_ref())?,
+            limit: request.limit,
         };
         self.query(&self.token_address, &msg).await
     }
+
+    /// Lists the allowances given to a spender, ordered by owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - An AllSpenderAllowancesRequest containing the spender and
+    ///   the page to return: the owner to start after and the page size,
+    ///   limited by the contract
+    ///
+    /// # Returns
+    ///
+    /// A Result containing an AllSpenderAllowancesResponse or an error
+    pub async fn all_spender_allowances(
+        &self,
+        request: AllSpenderAllowancesRequest,
+    ) -> Result<cw20::AllSpenderAllowancesResponse, RwaError> {
+        let msg = QueryMsg::AllSpenderAllowances {
+            spender: self.account_id(&request.spender)?.to_string(),
+            start_after: self.optional_account(request.start_after.as_ref())?,
+            limit: request.limit,
+        };
+        self.query(&self.token_address, &msg).await
+    }
+
+    /// Mints new tokens to a recipient. Only the minter of the token may mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `MintRequest` containing:
+    ///   - `from`: The minter
+    ///   - `recipient`: The account receiving the new tokens
+    ///   - `amount`: The amount of tokens to mint
+    ///   - `check_compliance`: Whether to check with the compliance contract
+    ///     that the recipient may receive the tokens before broadcasting
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// `RwaError::TokenPaused` while the token is paused, `RwaError::NotCompliant`
+    /// if the compliance check fails, or another error if the operation fails.
+    pub async fn mint(&self, request: MintRequest) -> Result<ExecuteResponse, RwaError> {
+        let recipient = self.account_id(&request.recipient)?.to_string();
+        self.ensure_not_paused().await?;
+        if request.check_compliance {
+            let compliant = self
+                .token_compliance(
+                    self.token_address.clone(),
+                    None,
+                    Some(recipient.clone()),
+                    Some(request.amount.into()),
+                )
+                .await?;
+            if !compliant {
+                return Err(RwaError::NotCompliant(recipient));
+            }
+        }
+
+        let msg = cw20::Cw20ExecuteMsg::Mint {
+            recipient,
+            amount: request.amount.into(),
+        };
+
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Burns tokens of the `from` account, e.g. on redemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `BurnRequest` containing the holder and the amount to burn
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn burn(&self, request: BurnRequest) -> Result<ExecuteResponse, RwaError> {
+        let msg = cw20::Cw20ExecuteMsg::Burn {
+            amount: request.amount.into(),
+        };
+
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Burns tokens of an owner, spending the allowance the owner gave the spender.
+    ///
+    /// The allowance is checked before broadcasting, as for
+    /// [`RwaClient::transfer_from`].
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `BurnFromRequest` containing:
+    ///   - `spender`: The account spending the allowance, which signs the transaction
+    ///   - `owner`: The account owning the tokens
+    ///   - `amount`: The amount of tokens to burn
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// `RwaError::InsufficientAllowance` or `RwaError::AllowanceExpired` if the
+    /// allowance does not cover the burn, or another error if the operation fails.
+    pub async fn burn_from(&self, request: BurnFromRequest) -> Result<ExecuteResponse, RwaError> {
+        let owner = self.account_id(&request.owner)?;
+        let spender = self.account_id(&request.spender)?;
+        self.check_allowance(&owner, &spender, request.amount)
+            .await?;
+
+        let msg = cw20::Cw20ExecuteMsg::BurnFrom {
+            owner: owner.to_string(),
+            amount: request.amount.into(),
+        };
+
+        self.execute(
+            &request.spender,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Hands the right to mint over to another account, or gives it up for
+    /// good when `new_minter` is `None`. Only the current minter may update it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - An `UpdateMinterRequest` containing the current minter and
+    ///   the new minter
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn update_minter(
+        &self,
+        request: UpdateMinterRequest,
+    ) -> Result<ExecuteResponse, RwaError> {
+        let msg = cw20::Cw20ExecuteMsg::UpdateMinter {
+            new_minter: self.optional_account(request.new_minter.as_ref())?,
+        };
+
+        self.execute(
+            &request.from,
+            &msg,
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Retrieves the minter of the token and its supply cap.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the MinterResponse, `None` if the token has no
+    /// minter, or an error
+    pub async fn minter(&self) -> Result<Option<cw20::MinterResponse>, RwaError> {
+        let msg = cw20::Cw20QueryMsg::Minter {};
+        self.query(&self.token_address, &msg).await
+    }
+
+    /// Pauses the token, halting all transfers and mints, e.g. during a
+    /// corporate action. Only an agent of the token may pause it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `PauseRequest` containing the agent
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn pause(&self, request: PauseRequest) -> Result<ExecuteResponse, RwaError> {
+        self.execute(
+            &request.from,
+            &ExecuteMsg::Pause {},
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Unpauses the token, resuming transfers and mints.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - A `PauseRequest` containing the agent
+    ///
+    /// # Returns
+    ///
+    /// A `ExecuteResponse` containing information about the transaction if successful,
+    /// or an error if the operation fails.
+    pub async fn unpause(&self, request: PauseRequest) -> Result<ExecuteResponse, RwaError> {
+        self.execute(
+            &request.from,
+            &ExecuteMsg::Unpause {},
+            self.token_address.clone(),
+            vec![],
+            request.signer.as_ref(),
+            request.gas_limit,
+        )
+        .await
+    }
+
+    /// Retrieves whether the token is paused.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing whether the token is paused or an error
+    pub async fn paused(&self) -> Result<bool, RwaError> {
+        self.query(&self.token_address, &QueryMsg::Paused {}).await
+    }
+
+    /// Fails with `RwaError::TokenPaused` if the token is paused.
+    ///
+    /// A token contract that does not support the `paused` query is not
+    /// checked, and the chain rejects the transaction if needed. Any other
+    /// failure of the query is returned, rather than taken as "not paused".
+    pub(crate) async fn ensure_not_paused(&self) -> Result<(), RwaError> {
+        let paused: Option<bool> = self
+            .optional_token_query(&QueryMsg::Paused {}, None)
+            .await?;
+        if paused.unwrap_or(false) {
+            return Err(RwaError::TokenPaused(self.token_address.clone()));
+        }
+        Ok(())
+    }
+
+    /// Queries the token contract, returning `None` if it does not support the
+    /// query.
+    ///
+    /// Any other contract error, e.g. a failing or out-of-gas query, is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The query message
+    /// * `height` - The height of the block whose state is queried, the
+    ///   latest if `None`
+    async fn optional_token_query<T: DeserializeOwned>(
+        &self,
+        msg: &QueryMsg,
+        height: Option<u64>,
+    ) -> Result<Option<T>, RwaError> {
+        match self.query_at_height(&self.token_address, msg, height).await {
+            Ok((result, _)) => Ok(Some(result)),
+            Err(e) if e.is_unsupported_query() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the bech32 form of an optional pagination key.
+    fn optional_account(&self, address: Option<&Address>) -> Result<Option<String>, RwaError> {
+        address
+            .map(|address| Ok(self.account_id(address)?.to_string()))
+            .transpose()
+    }
+}
+
+impl TxBatch<'_> {
+    /// Adds a token transfer from the batch sender to a recipient.
+    ///
+    /// The batch then fails without being broadcast while the token is paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `to` - The address receiving the tokens
+    /// * `amount` - The amount of tokens to transfer
+    pub fn transfer(mut self, to: &Address, amount: u128) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::Transfer {
+            recipient: self.account(to),
+            amount: amount.into(),
+        };
+        let token_address = self.client.token_address.clone();
+        self.when_not_paused()
+            .execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a transfer of tokens the batch sender was allowed to spend.
+    ///
+    /// Unlike [`RwaClient::transfer_from`], the allowance is not checked
+    /// before the batch is broadcast, only whether the token is paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The address owning the tokens
+    /// * `to` - The address receiving the tokens
+    /// * `amount` - The amount of tokens to transfer
+    pub fn transfer_from(mut self, owner: &Address, to: &Address, amount: u128) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::TransferFrom {
+            owner: self.account(owner),
+            recipient: self.account(to),
+            amount: amount.into(),
+        };
+        let token_address = self.client.token_address.clone();
+        self.when_not_paused()
+            .execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds an increase of the amount of tokens a spender may transfer from
+    /// the batch sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `spender` - The address allowed to spend the tokens
+    /// * `amount` - The amount added to the allowance
+    /// * `expires` - When the allowance expires, unchanged if `None`
+    pub fn increase_allowance(
+        mut self,
+        spender: &Address,
+        amount: u128,
+        expires: Option<Expiration>,
+    ) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::IncreaseAllowance {
+            spender: self.account(spender),
+            amount: amount.into(),
+            expires,
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a decrease of the amount of tokens a spender may transfer from
+    /// the batch sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `spender` - The address allowed to spend the tokens
+    /// * `amount` - The amount removed from the allowance
+    /// * `expires` - When the allowance expires, unchanged if `None`
+    pub fn decrease_allowance(
+        mut self,
+        spender: &Address,
+        amount: u128,
+        expires: Option<Expiration>,
+    ) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::DecreaseAllowance {
+            spender: self.account(spender),
+            amount: amount.into(),
+            expires,
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+}
+
+impl TxBatch<'_> {
+    /// Adds a mint of new tokens to a recipient, sent by the minter.
+    ///
+    /// Unlike [`RwaClient::mint`], no compliance check can be run before the
+    /// batch is broadcast, only whether the token is paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient` - The address receiving the new tokens
+    /// * `amount` - The amount of tokens to mint
+    pub fn mint(mut self, recipient: &Address, amount: u128) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::Mint {
+            recipient: self.account(recipient),
+            amount: amount.into(),
+        };
+        let token_address = self.client.token_address.clone();
+        self.when_not_paused()
+            .execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a burn of tokens of the batch sender.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The amount of tokens to burn
+    pub fn burn(self, amount: u128) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::Burn {
+            amount: amount.into(),
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a burn of tokens the batch sender was allowed to spend.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The address owning the tokens
+    /// * `amount` - The amount of tokens to burn
+    pub fn burn_from(mut self, owner: &Address, amount: u128) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::BurnFrom {
+            owner: self.account(owner),
+            amount: amount.into(),
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a freeze or unfreeze of a whole address.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address to freeze or unfreeze
+    /// * `freeze` - Whether to freeze the address
+    pub fn set_address_frozen(mut self, address: &Address, freeze: bool) -> Self {
+        let msg = ExecuteMsg::SetAddressFrozen {
+            address: self.account(address),
+            freeze,
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a freeze of part of the tokens of an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address holding the tokens
+    /// * `amount` - The amount of tokens to freeze
+    pub fn freeze_partial_tokens(mut self, address: &Address, amount: u128) -> Self {
+        let msg = ExecuteMsg::FreezePartialTokens {
+            address: self.account(address),
+            amount: amount.into(),
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds an unfreeze of part of the frozen tokens of an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - The address holding the tokens
+    /// * `amount` - The amount of tokens to unfreeze
+    pub fn unfreeze_partial_tokens(mut self, address: &Address, amount: u128) -> Self {
+        let msg = ExecuteMsg::UnfreezePartialTokens {
+            address: self.account(address),
+            amount: amount.into(),
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a hand over of the right to mint, given up for good if
+    /// `new_minter` is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_minter` - The address of the new minter
+    pub fn update_minter(mut self, new_minter: Option<&Address>) -> Self {
+        let msg = cw20::Cw20ExecuteMsg::UpdateMinter {
+            new_minter: new_minter.map(|minter| self.account(minter)),
+        };
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &msg, vec![])
+    }
+
+    /// Adds a pause of the token, sent by an agent.
+    pub fn pause(self) -> Self {
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &ExecuteMsg::Pause {}, vec![])
+    }
+
+    /// Adds an unpause of the token, sent by an agent.
+    pub fn unpause(self) -> Self {
+        let token_address = self.client.token_address.clone();
+        self.execute_contract(&token_address, &ExecuteMsg::Unpause {}, vec![])
+    }
+}
+
+/// Executions of the token contract beyond `cw20::Cw20ExecuteMsg`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum ExecuteMsg {
+    Pause {},
+    Unpause {},
+    SetAddressFrozen {
+        address: String,
+        freeze: bool,
+    },
+    BatchSetAddressFrozen {
+        addresses: Vec<String>,
+        freeze: Vec<bool>,
+    },
+    FreezePartialTokens {
+        address: String,
+        amount: Uint128,
+    },
+    UnfreezePartialTokens {
+        address: String,
+        amount: Uint128,
+    },
+    BatchFreezePartialTokens {
+        addresses: Vec<String>,
+        amounts: Vec<Uint128>,
+    },
+    BatchUnfreezePartialTokens {
+        addresses: Vec<String>,
+        amounts: Vec<Uint128>,
+    },
+}
+
+/// Queries of the token contract beyond `cw20::Cw20QueryMsg`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum QueryMsg {
+    AllSpenderAllowances {
+        spender: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    IsFrozen {
+        address: String,
+    },
+    FrozenTokens {
+        address: String,
+    },
+    Paused {},
 }
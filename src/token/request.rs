@@ -1,15 +1,135 @@
-use cosmrs::{crypto::secp256k1::SigningKey, Gas};
+use std::sync::Arc;
+
+use super::Expiration;
+use crate::{Address, GasSetting, Signer};
 
 /// Request structure for token transfers
 pub struct TransferMessageRequest {
-    pub from: String,
-    pub to: String,
+    pub from: Address,
+    pub to: Address,
     pub amount: u128,
-    pub signer: SigningKey,
-    pub gas_limit: Gas,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for transfers of tokens the spender was allowed to spend
+pub struct TransferFromRequest {
+    pub spender: Address,
+    pub owner: Address,
+    pub recipient: Address,
+    pub amount: u128,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
 
 /// Request structure for token info queries
 pub struct TokenInfoRequest {
-    pub address: String,
+    pub address: Address,
+}
+
+/// Request structure for increasing or decreasing the allowance of a spender
+pub struct AllowanceChangeRequest {
+    pub from: Address,
+    pub spender: Address,
+    pub amount: u128,
+    pub expires: Option<Expiration>,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for retrieving the allowance of a spender over an owner's tokens
+pub struct AllowanceRequest {
+    pub owner: Address,
+    pub spender: Address,
+}
+
+/// Request structure for listing the allowances given by an owner
+pub struct AllAllowancesRequest {
+    pub owner: Address,
+    pub start_after: Option<Address>,
+    pub limit: Option<u32>,
+}
+
+/// Request structure for listing the allowances given to a spender
+pub struct AllSpenderAllowancesRequest {
+    pub spender: Address,
+    pub start_after: Option<Address>,
+    pub limit: Option<u32>,
+}
+
+/// Request structure for minting new tokens to a recipient
+pub struct MintRequest {
+    pub from: Address,
+    pub recipient: Address,
+    pub amount: u128,
+    pub check_compliance: bool,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for burning tokens of the sender
+pub struct BurnRequest {
+    pub from: Address,
+    pub amount: u128,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for burning tokens the spender was allowed to spend
+pub struct BurnFromRequest {
+    pub spender: Address,
+    pub owner: Address,
+    pub amount: u128,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for handing over or giving up the right to mint
+pub struct UpdateMinterRequest {
+    pub from: Address,
+    pub new_minter: Option<Address>,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for freezing or unfreezing a whole address
+pub struct SetAddressFrozenRequest {
+    pub from: Address,
+    pub address: Address,
+    pub freeze: bool,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for freezing or unfreezing the addresses of a list at once
+pub struct BatchSetAddressFrozenRequest {
+    pub from: Address,
+    pub addresses: Vec<(Address, bool)>,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for freezing or unfreezing part of the tokens of an address
+pub struct PartialFreezeRequest {
+    pub from: Address,
+    pub address: Address,
+    pub amount: u128,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for freezing or unfreezing part of the tokens of
+/// several addresses at once
+pub struct BatchPartialFreezeRequest {
+    pub from: Address,
+    pub amounts: Vec<(Address, u128)>,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
+}
+
+/// Request structure for pausing or unpausing the token
+pub struct PauseRequest {
+    pub from: Address,
+    pub signer: Arc<dyn Signer>,
+    pub gas_limit: GasSetting,
 }
@@ -0,0 +1,613 @@
+//! In-memory transport for unit tests.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use cosmrs::proto::cosmos::auth::v1beta1::{
+    BaseAccount, QueryAccountInfoRequest, QueryAccountInfoResponse, QueryAccountRequest,
+    QueryAccountResponse,
+};
+use cosmrs::proto::cosmos::authz::v1beta1::{MsgExec, MsgExecResponse};
+use cosmrs::proto::cosmos::base::abci::v1beta1::{GasInfo, TxMsgData};
+use cosmrs::proto::cosmos::tx::v1beta1::SimulateResponse;
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    MsgExecuteContract, MsgExecuteContractResponse, QuerySmartContractStateRequest,
+    QuerySmartContractStateResponse,
+};
+use cosmrs::proto::prost::{Message, Name};
+use cosmrs::rpc::endpoint::{
+    abci_query::AbciQuery,
+    broadcast::{tx_async, tx_commit, tx_sync},
+    tx,
+};
+use cosmrs::rpc::{Code as RpcCode, ResponseError};
+use cosmrs::tendermint::abci::{self, Code};
+use cosmrs::tendermint::{block, Hash};
+use cosmrs::{AccountId, Any, Gas, Tx};
+use sha2::{Digest, Sha256};
+
+use super::Transport;
+use crate::{AccountTypes, RwaError};
+
+const SIMULATE_PATH: &str = "/cosmos.tx.v1beta1.Service/Simulate";
+const SMART_CONTRACT_STATE_PATH: &str = "/cosmwasm.wasm.v1.Query/SmartContractState";
+const ACCOUNT_PATH: &str = "/cosmos.auth.v1beta1.Query/Account";
+const ACCOUNT_INFO_PATH: &str = "/cosmos.auth.v1beta1.Query/AccountInfo";
+
+type QueryHandler = Box<dyn Fn(&[u8]) -> AbciQuery + Send + Sync>;
+type ContractHandler = Box<dyn Fn(&[u8]) -> Result<Vec<u8>, String> + Send + Sync>;
+
+/// A scriptable in-memory [`Transport`].
+///
+/// Query responses are registered per gRPC path, either as queued canned
+/// responses or as handlers inspecting the request. Smart contract queries and
+/// executions can be answered per contract address. Transactions are accepted
+/// and committed immediately, whatever the broadcast mode, unless a broadcast
+/// response has been queued, and every query and broadcast transaction is
+/// recorded so tests can assert on what the client sent.
+#[derive(Default)]
+pub struct MockTransport {
+    state: Mutex<MockState>,
+}
+
+#[derive(Default)]
+struct MockState {
+    query_responses: HashMap<String, VecDeque<AbciQuery>>,
+    query_handlers: HashMap<String, QueryHandler>,
+    contract_handlers: HashMap<String, ContractHandler>,
+    execute_handlers: HashMap<String, ContractHandler>,
+    accounts: HashMap<String, Any>,
+    broadcast_responses: VecDeque<tx_commit::Response>,
+    txs: HashMap<Hash, tx::Response>,
+    queries: Vec<(String, Vec<u8>)>,
+    broadcasts: Vec<Vec<u8>>,
+    height: u64,
+    pending_tx_lookups: usize,
+    tx_lookup_error: Option<String>,
+}
+
+impl std::fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("MockTransport")
+            .field("queries", &state.queries.len())
+            .field("broadcasts", &state.broadcasts.len())
+            .finish()
+    }
+}
+
+impl MockTransport {
+    /// Creates an empty mock transport.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a response for the next query on `path`.
+    ///
+    /// Queued responses take precedence over handlers and are consumed in order.
+    pub fn push_query_response(&self, path: &str, response: AbciQuery) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .query_responses
+            .entry(path.to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Answers every query on `path` with `handler`, which receives the encoded request.
+    pub fn on_query<F>(&self, path: &str, handler: F)
+    where
+        F: Fn(&[u8]) -> AbciQuery + Send + Sync + 'static,
+    {
+        let mut state = self.state.lock().unwrap();
+        state
+            .query_handlers
+            .insert(path.to_string(), Box::new(handler));
+    }
+
+    /// Answers smart contract queries sent to `contract` with `handler`.
+    ///
+    /// The handler receives the JSON query message and returns either the JSON
+    /// response or the contract error.
+    pub fn on_contract_query<F>(&self, contract: &str, handler: F)
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>, String> + Send + Sync + 'static,
+    {
+        let mut state = self.state.lock().unwrap();
+        state
+            .contract_handlers
+            .insert(contract.to_string(), Box::new(handler));
+    }
+
+    /// Executes the messages sent to `contract` with `handler`.
+    ///
+    /// The handler receives the JSON execute message and returns either the
+    /// response data or the contract error, which fails the whole transaction.
+    /// Contracts without a handler accept every message and return no data.
+    pub fn on_contract_execute<F>(&self, contract: &str, handler: F)
+    where
+        F: Fn(&[u8]) -> Result<Vec<u8>, String> + Send + Sync + 'static,
+    {
+        let mut state = self.state.lock().unwrap();
+        state
+            .execute_handlers
+            .insert(contract.to_string(), Box::new(handler));
+    }
+
+    /// Answers every smart contract query sent to `contract` with `response`.
+    pub fn set_contract_response(
+        &self,
+        contract: &str,
+        response: &impl serde::Serialize,
+    ) -> Result<(), RwaError> {
+        let response = cosmwasm_std::to_json_vec(response)?;
+        self.on_contract_query(contract, move |_| Ok(response.clone()));
+        Ok(())
+    }
+
+    /// Sets the height of the latest block, at which smart contract queries
+    /// are answered. Every committed transaction adds a block.
+    pub fn set_height(&self, height: u64) {
+        self.state.lock().unwrap().height = height;
+    }
+
+    /// Registers an on-chain account.
+    pub fn set_account(&self, account_id: &AccountId, account_number: u64, sequence: u64) {
+        let account = BaseAccount {
+            address: account_id.to_string(),
+            pub_key: None,
+            account_number,
+            sequence,
+        };
+        self.set_account_any(
+            account_id,
+            Any {
+                type_url: BaseAccount::type_url(),
+                value: account.encode_to_vec(),
+            },
+        );
+    }
+
+    /// Registers an on-chain account of any type, e.g. a vesting account.
+    ///
+    /// The `AccountInfo` query answers for accounts of the types known to
+    /// `AccountTypes` by default.
+    pub fn set_account_any(&self, account_id: &AccountId, account: Any) {
+        let mut state = self.state.lock().unwrap();
+        state.accounts.insert(account_id.to_string(), account);
+    }
+
+    /// Answers every simulation with the given gas usage.
+    pub fn set_simulated_gas(&self, gas_used: Gas) {
+        self.on_query(SIMULATE_PATH, move |_| {
+            Self::query_ok(
+                SimulateResponse {
+                    gas_info: Some(GasInfo {
+                        gas_wanted: 0,
+                        gas_used,
+                    }),
+                    result: None,
+                }
+                .encode_to_vec(),
+            )
+        });
+    }
+
+    /// Queues the response for the next broadcast transaction.
+    pub fn push_broadcast_response(&self, response: tx_commit::Response) {
+        let mut state = self.state.lock().unwrap();
+        state.broadcast_responses.push_back(response);
+    }
+
+    /// Registers a committed transaction returned by [`Transport::tx`].
+    pub fn insert_tx(&self, response: tx::Response) {
+        let mut state = self.state.lock().unwrap();
+        state.txs.insert(response.hash, response);
+    }
+
+    /// Makes the next `lookups` transaction lookups report the transaction as
+    /// not found yet, as if it were still waiting in the mempool.
+    pub fn delay_tx_inclusion(&self, lookups: usize) {
+        self.state.lock().unwrap().pending_tx_lookups = lookups;
+    }
+
+    /// Makes every transaction lookup fail, as if the node were unreachable.
+    pub fn fail_tx_lookups(&self, reason: &str) {
+        self.state.lock().unwrap().tx_lookup_error = Some(reason.to_string());
+    }
+
+    /// Returns every query performed, as `(path, request)` pairs.
+    pub fn queries(&self) -> Vec<(String, Vec<u8>)> {
+        self.state.lock().unwrap().queries.clone()
+    }
+
+    /// Returns the raw bytes of every broadcast transaction.
+    pub fn broadcasts(&self) -> Vec<Vec<u8>> {
+        self.state.lock().unwrap().broadcasts.clone()
+    }
+
+    /// Returns every broadcast transaction, decoded.
+    pub fn broadcast_txs(&self) -> Result<Vec<Tx>, RwaError> {
+        self.broadcasts()
+            .iter()
+            .map(|tx_bytes| decode_tx(tx_bytes))
+            .collect()
+    }
+
+    /// Builds a successful query result carrying `value`.
+    pub fn query_ok(value: Vec<u8>) -> AbciQuery {
+        AbciQuery {
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Builds a failed query result.
+    pub fn query_error(code: u32, codespace: &str, log: &str) -> AbciQuery {
+        AbciQuery {
+            code: Code::from(code),
+            codespace: codespace.to_string(),
+            log: log.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Builds a broadcast result, successful if both codes are zero.
+    ///
+    /// The hash is filled in from the broadcast transaction.
+    pub fn broadcast_response(
+        check_tx_code: u32,
+        deliver_tx_code: u32,
+        codespace: &str,
+        log: &str,
+    ) -> tx_commit::Response {
+        tx_commit::Response {
+            check_tx: abci::response::CheckTx {
+                code: Code::from(check_tx_code),
+                codespace: codespace.to_string(),
+                log: log.to_string(),
+                ..Default::default()
+            },
+            tx_result: abci::types::ExecTxResult {
+                code: Code::from(deliver_tx_code),
+                codespace: codespace.to_string(),
+                log: log.to_string(),
+                ..Default::default()
+            },
+            hash: Hash::None,
+            height: block::Height::from(u32::from(check_tx_code == 0)),
+        }
+    }
+
+    /// Records a broadcast transaction and commits it in a new block, unless
+    /// a queued response says otherwise.
+    fn commit(state: &mut MockState, tx_bytes: Vec<u8>) -> Result<tx_commit::Response, RwaError> {
+        let tx = decode_tx(&tx_bytes)?;
+        state.broadcasts.push(tx_bytes.clone());
+
+        let mut response = state.broadcast_responses.pop_front().unwrap_or_else(|| {
+            let gas_wanted = tx.auth_info.fee.gas_limit as i64;
+            tx_commit::Response {
+                check_tx: abci::response::CheckTx {
+                    gas_wanted,
+                    ..Default::default()
+                },
+                tx_result: abci::types::ExecTxResult {
+                    gas_wanted,
+                    gas_used: gas_wanted,
+                    ..Self::execute_tx(state, &tx)
+                },
+                hash: Hash::None,
+                height: block::Height::from(1u32),
+            }
+        });
+        response.hash = tx_hash(&tx_bytes);
+
+        // A transaction rejected by CheckTx never makes it into a block
+        if response.check_tx.code.is_ok() {
+            state.height += 1;
+            response.height = block_height(state.height)?;
+            state.txs.insert(
+                response.hash,
+                tx::Response {
+                    hash: response.hash,
+                    height: response.height,
+                    index: 0,
+                    tx_result: response.tx_result.clone(),
+                    tx: tx_bytes,
+                    proof: None,
+                },
+            );
+        }
+
+        Ok(response)
+    }
+
+    /// Executes the messages of a transaction, stopping at the first failure
+    /// like the chain does.
+    fn execute_tx(state: &MockState, tx: &Tx) -> abci::types::ExecTxResult {
+        let mut msg_responses = Vec::new();
+        let mut events = Vec::new();
+
+        for (index, msg) in tx.body.messages.iter().enumerate() {
+            match Self::execute_msg(state, msg) {
+                Ok((response, msg_events)) => {
+                    msg_responses.push(response);
+                    events.extend(tag_events(msg_events, "msg_index", index));
+                }
+                Err(error) => {
+                    return abci::types::ExecTxResult {
+                        code: Code::from(5),
+                        codespace: "wasm".to_string(),
+                        log: format!(
+                            "failed to execute message; message index: {}: {}: execute wasm contract failed",
+                            index, error
+                        ),
+                        ..Default::default()
+                    };
+                }
+            }
+        }
+
+        #[allow(deprecated)]
+        let data = TxMsgData {
+            data: Vec::new(),
+            msg_responses,
+        };
+        abci::types::ExecTxResult {
+            data: data.encode_to_vec().into(),
+            events,
+            ..Default::default()
+        }
+    }
+
+    /// Executes one message, returning its response and events.
+    ///
+    /// The messages of a `MsgExec` are executed in turn, and their events
+    /// tagged with their `authz_msg_index` like the authz module does.
+    fn execute_msg(state: &MockState, msg: &Any) -> Result<(Any, Vec<abci::Event>), String> {
+        if msg.type_url == MsgExec::type_url() {
+            let exec = MsgExec::decode(msg.value.as_slice()).map_err(|e| e.to_string())?;
+            let mut results = Vec::new();
+            let mut events = Vec::new();
+            for (index, msg) in exec.msgs.iter().enumerate() {
+                let (response, msg_events) = Self::execute_msg(state, msg)?;
+                results.push(response.value);
+                events.extend(tag_events(msg_events, "authz_msg_index", index));
+            }
+            let response = Any {
+                type_url: MsgExecResponse::type_url(),
+                value: MsgExecResponse { results }.encode_to_vec(),
+            };
+            return Ok((response, events));
+        }
+
+        if msg.type_url != MsgExecuteContract::type_url() {
+            let response = Any {
+                type_url: format!("{}Response", msg.type_url),
+                value: Vec::new(),
+            };
+            let event = abci::Event::new("message", [("action", msg.type_url.as_str())]);
+            return Ok((response, vec![event]));
+        }
+
+        let execute =
+            MsgExecuteContract::decode(msg.value.as_slice()).map_err(|e| e.to_string())?;
+        let data = match state.execute_handlers.get(&execute.contract) {
+            Some(handler) => handler(&execute.msg)?,
+            None => Vec::new(),
+        };
+        let response = Any {
+            type_url: MsgExecuteContractResponse::type_url(),
+            value: MsgExecuteContractResponse { data }.encode_to_vec(),
+        };
+        let event = abci::Event::new(
+            "execute",
+            [("_contract_address", execute.contract.as_str())],
+        );
+        Ok((response, vec![event]))
+    }
+
+    fn query_account(state: &MockState, data: &[u8]) -> AbciQuery {
+        let address = match QueryAccountRequest::decode(data) {
+            Ok(request) => request.address,
+            Err(e) => return Self::query_error(2, "sdk", &e.to_string()),
+        };
+        match state.accounts.get(&address) {
+            Some(account) => Self::query_ok(
+                QueryAccountResponse {
+                    account: Some(account.clone()),
+                }
+                .encode_to_vec(),
+            ),
+            None => Self::account_not_found(&address),
+        }
+    }
+
+    fn query_account_info(state: &MockState, data: &[u8]) -> AbciQuery {
+        let address = match QueryAccountInfoRequest::decode(data) {
+            Ok(request) => request.address,
+            Err(e) => return Self::query_error(2, "sdk", &e.to_string()),
+        };
+        let Some(account) = state.accounts.get(&address) else {
+            return Self::account_not_found(&address);
+        };
+        let account_id = match address.parse::<AccountId>() {
+            Ok(account_id) => account_id,
+            Err(e) => return Self::query_error(2, "sdk", &e.to_string()),
+        };
+        match AccountTypes::default().base_account(&account_id, account) {
+            Ok(info) => {
+                Self::query_ok(QueryAccountInfoResponse { info: Some(info) }.encode_to_vec())
+            }
+            Err(e) => Self::query_error(2, "sdk", &e.to_string()),
+        }
+    }
+
+    fn account_not_found(address: &str) -> AbciQuery {
+        Self::query_error(
+            22,
+            "sdk",
+            &format!("account {} not found: key not found", address),
+        )
+    }
+
+    fn query_contract(state: &MockState, data: &[u8]) -> AbciQuery {
+        let request = match QuerySmartContractStateRequest::decode(data) {
+            Ok(request) => request,
+            Err(e) => return Self::query_error(2, "sdk", &e.to_string()),
+        };
+        let Some(handler) = state.contract_handlers.get(&request.address) else {
+            return Self::query_error(2, "wasm", &format!("no such contract: {}", request.address));
+        };
+        let height = match block_height(state.height) {
+            Ok(height) => height,
+            Err(e) => return Self::query_error(1, "sdk", &e.to_string()),
+        };
+        match handler(&request.query_data) {
+            Ok(data) => AbciQuery {
+                height,
+                ..Self::query_ok(QuerySmartContractStateResponse { data }.encode_to_vec())
+            },
+            Err(error) => {
+                Self::query_error(9, "wasm", &format!("{}: query wasm contract failed", error))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn abci_query(&self, path: &str, data: Vec<u8>) -> Result<AbciQuery, RwaError> {
+        let mut state = self.state.lock().unwrap();
+        state.queries.push((path.to_string(), data.clone()));
+
+        if let Some(response) = state
+            .query_responses
+            .get_mut(path)
+            .and_then(VecDeque::pop_front)
+        {
+            return Ok(response);
+        }
+        if let Some(handler) = state.query_handlers.get(path) {
+            return Ok(handler(&data));
+        }
+        if path == SMART_CONTRACT_STATE_PATH {
+            return Ok(Self::query_contract(&state, &data));
+        }
+        if path == ACCOUNT_PATH {
+            return Ok(Self::query_account(&state, &data));
+        }
+        if path == ACCOUNT_INFO_PATH {
+            return Ok(Self::query_account_info(&state, &data));
+        }
+
+        Ok(Self::query_error(
+            6,
+            "sdk",
+            &format!("unknown query path {}", path),
+        ))
+    }
+
+    async fn abci_query_at(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: u64,
+    ) -> Result<AbciQuery, RwaError> {
+        // Only the state of the latest block is kept
+        let latest = self.state.lock().unwrap().height;
+        if height != latest {
+            return Ok(Self::query_error(
+                26,
+                "sdk",
+                &format!(
+                    "height {} is not available, latest height is {}",
+                    height, latest
+                ),
+            ));
+        }
+        self.abci_query(path, data).await
+    }
+
+    async fn broadcast_tx_commit(
+        &self,
+        tx_bytes: Vec<u8>,
+    ) -> Result<tx_commit::Response, RwaError> {
+        let mut state = self.state.lock().unwrap();
+        Self::commit(&mut state, tx_bytes)
+    }
+
+    async fn broadcast_tx_sync(&self, tx_bytes: Vec<u8>) -> Result<tx_sync::Response, RwaError> {
+        let mut state = self.state.lock().unwrap();
+        let response = Self::commit(&mut state, tx_bytes)?;
+        Ok(tx_sync::Response {
+            codespace: response.check_tx.codespace,
+            code: response.check_tx.code,
+            data: response.check_tx.data,
+            log: response.check_tx.log,
+            hash: response.hash,
+        })
+    }
+
+    async fn broadcast_tx_async(&self, tx_bytes: Vec<u8>) -> Result<tx_async::Response, RwaError> {
+        let mut state = self.state.lock().unwrap();
+        let response = Self::commit(&mut state, tx_bytes)?;
+        Ok(tx_async::Response {
+            codespace: String::new(),
+            code: Code::Ok,
+            data: Default::default(),
+            log: String::new(),
+            hash: response.hash,
+        })
+    }
+
+    async fn tx(&self, hash: Hash) -> Result<tx::Response, RwaError> {
+        let mut state = self.state.lock().unwrap();
+        let found = if state.pending_tx_lookups > 0 {
+            state.pending_tx_lookups -= 1;
+            None
+        } else {
+            state.txs.get(&hash).cloned()
+        };
+        if let Some(reason) = &state.tx_lookup_error {
+            return Err(RwaError::Transport(cosmrs::rpc::Error::client_internal(
+                reason.clone(),
+            )));
+        }
+        // CometBFT answers the lookup of an unknown transaction with an internal error
+        found.ok_or_else(|| {
+            RwaError::Transport(cosmrs::rpc::Error::response(ResponseError::new(
+                RpcCode::InternalError,
+                Some(format!("tx ({}) not found", hash)),
+            )))
+        })
+    }
+}
+
+/// Decodes a broadcast transaction.
+fn decode_tx(tx_bytes: &[u8]) -> Result<Tx, RwaError> {
+    Tx::from_bytes(tx_bytes).map_err(|e| RwaError::Encoding(e.to_string()))
+}
+
+/// Converts a block height of the mock chain.
+fn block_height(height: u64) -> Result<block::Height, RwaError> {
+    block::Height::try_from(height).map_err(|e| RwaError::Encoding(e.to_string()))
+}
+
+/// Computes the CometBFT hash of a transaction.
+fn tx_hash(tx_bytes: &[u8]) -> Hash {
+    Hash::Sha256(Sha256::digest(tx_bytes).into())
+}
+
+/// Adds the index of the message that emitted them to events.
+fn tag_events(events: Vec<abci::Event>, key: &str, index: usize) -> Vec<abci::Event> {
+    events
+        .into_iter()
+        .map(|mut event| {
+            event.attributes.push((key, index.to_string()).into());
+            event
+        })
+        .collect()
+}
@@ -0,0 +1,143 @@
+//! Transport layer for the RWA SDK.
+//!
+//! [`RwaClient`](crate::RwaClient) talks to the chain exclusively through the
+//! [`Transport`] trait. The CometBFT [`HttpClient`] is the default
+//! implementation. With the `mock` feature, `MockTransport` is an in-memory
+//! implementation for unit testing code built on top of the SDK.
+
+use async_trait::async_trait;
+use cosmrs::rpc::endpoint::{
+    abci_query::AbciQuery,
+    broadcast::{tx_async, tx_commit, tx_sync},
+    tx,
+};
+use cosmrs::rpc::{Client, HttpClient};
+use cosmrs::tendermint::block::Height;
+use cosmrs::tendermint::Hash;
+
+use crate::RwaError;
+
+#[cfg(feature = "mock")]
+mod mock;
+
+#[cfg(feature = "mock")]
+pub use mock::MockTransport;
+
+/// A connection to a chain node able to answer queries and accept transactions.
+#[async_trait]
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    /// Performs an ABCI query.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The gRPC method path of the query
+    /// * `data` - The protobuf encoded query request
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the raw ABCI query result or an error
+    async fn abci_query(&self, path: &str, data: Vec<u8>) -> Result<AbciQuery, RwaError>;
+
+    /// Performs an ABCI query against the state at a given block height.
+    ///
+    /// The client relies on it to read related state, such as a balance and
+    /// its frozen part, consistently. A transport unable to query past heights
+    /// must return an error rather than the latest state.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The gRPC method path of the query
+    /// * `data` - The protobuf encoded query request
+    /// * `height` - The height of the block whose state is queried
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the raw ABCI query result or an error
+    async fn abci_query_at(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: u64,
+    ) -> Result<AbciQuery, RwaError>;
+
+    /// Broadcasts a transaction and waits until it is committed in a block.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_bytes` - The encoded, signed transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the `CheckTx` and `DeliverTx` results or an error
+    async fn broadcast_tx_commit(&self, tx_bytes: Vec<u8>)
+        -> Result<tx_commit::Response, RwaError>;
+
+    /// Broadcasts a transaction and returns once it passed `CheckTx`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_bytes` - The encoded, signed transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the `CheckTx` result and the transaction hash or an error
+    async fn broadcast_tx_sync(&self, tx_bytes: Vec<u8>) -> Result<tx_sync::Response, RwaError>;
+
+    /// Broadcasts a transaction without waiting for `CheckTx`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_bytes` - The encoded, signed transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the transaction hash or an error
+    async fn broadcast_tx_async(&self, tx_bytes: Vec<u8>) -> Result<tx_async::Response, RwaError>;
+
+    /// Looks up a committed transaction by hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - The hash of the transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the committed transaction or an error
+    async fn tx(&self, hash: Hash) -> Result<tx::Response, RwaError>;
+}
+
+#[async_trait]
+impl Transport for HttpClient {
+    async fn abci_query(&self, path: &str, data: Vec<u8>) -> Result<AbciQuery, RwaError> {
+        Ok(Client::abci_query(self, Some(path.to_string()), data, None, false).await?)
+    }
+
+    async fn abci_query_at(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: u64,
+    ) -> Result<AbciQuery, RwaError> {
+        let height = Height::try_from(height).map_err(|e| RwaError::Encoding(e.to_string()))?;
+        Ok(Client::abci_query(self, Some(path.to_string()), data, Some(height), false).await?)
+    }
+
+    async fn broadcast_tx_commit(
+        &self,
+        tx_bytes: Vec<u8>,
+    ) -> Result<tx_commit::Response, RwaError> {
+        Ok(Client::broadcast_tx_commit(self, tx_bytes).await?)
+    }
+
+    async fn broadcast_tx_sync(&self, tx_bytes: Vec<u8>) -> Result<tx_sync::Response, RwaError> {
+        Ok(Client::broadcast_tx_sync(self, tx_bytes).await?)
+    }
+
+    async fn broadcast_tx_async(&self, tx_bytes: Vec<u8>) -> Result<tx_async::Response, RwaError> {
+        Ok(Client::broadcast_tx_async(self, tx_bytes).await?)
+    }
+
+    async fn tx(&self, hash: Hash) -> Result<tx::Response, RwaError> {
+        Ok(Client::tx(self, hash, false).await?)
+    }
+}
@@ -0,0 +1,71 @@
+use erc3643sdk::{Address, RwaError};
+
+/// Test vectors of EIP-55.
+const CHECKSUMMED: [&str; 4] = [
+    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+];
+
+#[test]
+fn test_hex_address_is_checksummed() {
+    for checksummed in CHECKSUMMED {
+        let address: Address = checksummed.to_lowercase().parse().unwrap();
+        assert_eq!(address.to_hex().unwrap(), checksummed);
+        assert_eq!(address.to_string(), checksummed);
+        assert_eq!(checksummed.parse::<Address>().unwrap(), address);
+    }
+}
+
+#[test]
+fn test_invalid_checksum_is_rejected() {
+    let err = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+        .parse::<Address>()
+        .unwrap_err();
+    assert!(
+        matches!(err, RwaError::InvalidAddress { ref reason, .. } if reason.contains("EIP-55"))
+    );
+
+    let upper = "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED";
+    assert!(upper.parse::<Address>().is_ok());
+    assert!("0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea"
+        .parse::<Address>()
+        .is_err());
+}
+
+#[test]
+fn test_bech32_and_hex_forms_convert() {
+    let address: Address = CHECKSUMMED[0].parse().unwrap();
+    let bech32 = address.to_bech32("kii").unwrap();
+    assert!(bech32.starts_with("kii1"));
+
+    let parsed: Address = bech32.parse().unwrap();
+    assert_eq!(parsed.hrp(), Some("kii"));
+    assert_eq!(parsed.to_hex().unwrap(), CHECKSUMMED[0]);
+    assert_eq!(parsed.to_string(), bech32);
+    assert_eq!(parsed, address);
+}
+
+#[test]
+fn test_invalid_bech32_checksum_is_rejected() {
+    let bech32 = Address::from_bytes([7; 20]).to_bech32("kii").unwrap();
+    let mut corrupted = bech32.clone();
+    let last = corrupted.pop().unwrap();
+    corrupted.push(if last == 'q' { 'p' } else { 'q' });
+
+    assert!(matches!(
+        corrupted.parse::<Address>(),
+        Err(RwaError::InvalidAddress { .. })
+    ));
+}
+
+#[test]
+fn test_contract_address_has_no_evm_form() {
+    let contract = Address::from_bytes([1; 32]);
+    assert!(contract.to_bech32("kii").is_ok());
+    assert!(matches!(
+        contract.to_hex(),
+        Err(RwaError::InvalidAddress { .. })
+    ));
+}
@@ -0,0 +1,177 @@
+use std::path::PathBuf;
+
+use cosmrs::AccountId;
+use erc3643sdk::{BroadcastMode, GasConfig, RwaClient, RwaClientBuilder, RwaError};
+
+fn address(id: u8) -> String {
+    AccountId::new("kii", &[id; 32]).unwrap().to_string()
+}
+
+fn complete_builder() -> RwaClientBuilder {
+    RwaClient::builder()
+        .chain_id("rwa-test")
+        .token_address(&address(1))
+        .identity_address(&address(2))
+        .compliance_address(&address(3))
+        .gas_prices("0.025ukii")
+}
+
+fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("erc3643sdk-{}-{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_build_with_named_setters() {
+    assert!(complete_builder().build().is_ok());
+}
+
+#[test]
+fn test_account_prefix_defaults_to_token_address_prefix() {
+    assert_eq!(complete_builder().build().unwrap().account_prefix(), "kii");
+
+    let client = complete_builder().account_prefix("ethm").build().unwrap();
+    assert_eq!(client.account_prefix(), "ethm");
+}
+
+#[test]
+fn test_missing_setting_is_rejected() {
+    let err = RwaClient::builder().build().unwrap_err();
+    assert!(matches!(err, RwaError::Config(ref reason) if reason.contains("chain_id")));
+}
+
+#[test]
+fn test_invalid_address_is_rejected() {
+    let err = complete_builder()
+        .identity_address("kii1identity...")
+        .build()
+        .unwrap_err();
+    assert!(
+        matches!(err, RwaError::InvalidAddress { ref address, .. } if address == "kii1identity...")
+    );
+}
+
+#[test]
+fn test_invalid_chain_id_is_rejected() {
+    let err = complete_builder().chain_id("").build().unwrap_err();
+    assert!(matches!(err, RwaError::Config(_)));
+}
+
+#[test]
+fn test_load_toml_file() {
+    let path = write_temp_file(
+        "network.toml",
+        &format!(
+            r#"
+            rpc_url = "http://localhost:26657"
+            chain_id = "rwa-test"
+            token_address = "{}"
+            identity_address = "{}"
+            compliance_address = "{}"
+            gas_prices = "0.025ukii,0.1uusdc"
+            gas_floor = 50000
+            broadcast_mode = "sync"
+            "#,
+            address(1),
+            address(2),
+            address(3)
+        ),
+    );
+
+    let client = RwaClientBuilder::from_file(&path).unwrap().build();
+    std::fs::remove_file(&path).unwrap();
+    assert!(client.is_ok());
+}
+
+#[test]
+fn test_load_json_profile() {
+    let path = write_temp_file(
+        "profiles.json",
+        &format!(
+            r#"{{
+                "testnet": {{
+                    "chain_id": "rwa-testnet-1",
+                    "token_address": "{}",
+                    "identity_address": "{}",
+                    "compliance_address": "{}",
+                    "gas_prices": "0.025ukii"
+                }},
+                "mainnet": {{ "chain_id": "rwa-1" }}
+            }}"#,
+            address(1),
+            address(2),
+            address(3)
+        ),
+    );
+
+    let testnet = RwaClientBuilder::from_profile(&path, "testnet")
+        .unwrap()
+        .build();
+    let devnet = RwaClientBuilder::from_profile(&path, "devnet");
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(testnet.is_ok());
+    assert!(matches!(devnet, Err(RwaError::Config(_))));
+}
+
+#[test]
+fn test_env_overrides_settings() {
+    std::env::set_var("RWA_CHAIN_ID", "rwa-env-1");
+    std::env::set_var("RWA_GAS_FLOOR", "not-a-number");
+    let invalid = complete_builder().with_env();
+    std::env::set_var("RWA_GAS_FLOOR", "3");
+    std::env::set_var("RWA_GAS_PRICES", "0.5uusdc");
+    let valid = complete_builder().with_env();
+    std::env::remove_var("RWA_CHAIN_ID");
+    std::env::remove_var("RWA_GAS_FLOOR");
+    std::env::remove_var("RWA_GAS_PRICES");
+
+    assert!(matches!(invalid, Err(RwaError::Config(_))));
+    assert!(valid.unwrap().build().is_ok());
+}
+
+#[test]
+fn test_invalid_gas_prices_are_rejected() {
+    let err = complete_builder().gas_prices("ukii").build().unwrap_err();
+    assert!(matches!(err, RwaError::Config(_)));
+
+    let err = complete_builder().gas_prices(" , ").build().unwrap_err();
+    assert!(matches!(err, RwaError::Config(_)));
+}
+
+#[test]
+fn test_inverted_gas_bounds_are_rejected() {
+    let inverted = GasConfig {
+        floor: 200_000,
+        ceiling: 100_000,
+    };
+    let err = complete_builder().gas_config(inverted).build().unwrap_err();
+    assert!(matches!(err, RwaError::Config(_)));
+
+    let client = complete_builder().build().unwrap();
+    let err = client.clone().with_gas_config(inverted).unwrap_err();
+    assert!(matches!(err, RwaError::Config(ref reason) if reason.contains("exceeds")));
+    assert!(client
+        .with_gas_config(GasConfig {
+            floor: 100_000,
+            ceiling: 100_000,
+        })
+        .is_ok());
+}
+
+#[test]
+fn test_parse_broadcast_mode() {
+    assert_eq!(
+        "Sync".parse::<BroadcastMode>().unwrap(),
+        BroadcastMode::Sync
+    );
+    assert_eq!(
+        "block".parse::<BroadcastMode>().unwrap(),
+        BroadcastMode::Commit
+    );
+    assert!(matches!(
+        "batch".parse::<BroadcastMode>(),
+        Err(RwaError::Config(_))
+    ));
+}
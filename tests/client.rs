@@ -0,0 +1,2433 @@
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+use async_trait::async_trait;
+
+use cosmrs::proto::cosmos::auth::v1beta1::{
+    BaseAccount, ModuleAccount, QueryAccountInfoRequest, QueryAccountInfoResponse,
+    QueryAccountRequest,
+};
+use cosmrs::proto::cosmos::authz::v1beta1::{MsgExec, MsgGrant, MsgRevoke};
+use cosmrs::proto::cosmos::base::node::v1beta1::ConfigResponse;
+use cosmrs::proto::cosmos::crypto::multisig::v1beta1::MultiSignature;
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    AllowedMsgAllowance, BasicAllowance, Grant as FeeGrantProto, MsgGrantAllowance,
+    PeriodicAllowance, QueryAllowanceResponse,
+};
+use cosmrs::proto::cosmos::vesting::v1beta1::{BaseVestingAccount, ContinuousVestingAccount};
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    ContractExecutionAuthorization, MaxCallsLimit, MsgExecuteContract,
+};
+use cosmrs::proto::prost::Message;
+use cosmrs::rpc::endpoint::abci_query::AbciQuery;
+use cosmrs::rpc::endpoint::broadcast::{tx_async, tx_commit, tx_sync};
+use cosmrs::rpc::endpoint::tx;
+use cosmrs::tendermint::Hash;
+use cosmrs::tx::{ModeInfo, SignMode as ProtoSignMode};
+use cosmrs::{AccountId, Coin};
+use erc3643sdk::account::{EthAccount, ETH_ACCOUNT_TYPE_URL};
+use erc3643sdk::authz::request::GrantRequest;
+use erc3643sdk::feegrant::request::{FeeAllowanceRequest, GrantFeeAllowanceRequest};
+use erc3643sdk::identity::Claim;
+use erc3643sdk::signer::ETH_SECP256K1_TYPE_URL;
+use erc3643sdk::token::request::{
+    AllAllowancesRequest, AllSpenderAllowancesRequest, AllowanceChangeRequest,
+    BatchPartialFreezeRequest, BurnFromRequest, MintRequest, PauseRequest, SetAddressFrozenRequest,
+    TokenInfoRequest, TransferFromRequest, TransferMessageRequest, UpdateMinterRequest,
+};
+use erc3643sdk::token::Expiration;
+use erc3643sdk::{
+    Address, Authorization, BroadcastMode, ContractGrant, ContractLimit, FeeAllowance, GasConfig,
+    GasSetting, KeyType, LocalSigner, MockTransport, MultisigAccount, PartialSignature, RwaClient,
+    RwaClientBuilder, RwaError, SignMode, Signer, Transport, UnsignedTx,
+};
+
+const CHAIN_ID: &str = "rwa-test";
+const GAS_PRICES: &str = "0.025ukii,0.1uusdc";
+const CONFIG_PATH: &str = "/cosmos.base.node.v1beta1.Service/Config";
+
+fn contract_address(id: u8) -> String {
+    AccountId::new("cosmos", &[id; 32]).unwrap().to_string()
+}
+
+fn address_of(id: u8) -> Address {
+    contract_address(id).parse().unwrap()
+}
+
+fn setup() -> (RwaClient, Arc<MockTransport>, Arc<dyn Signer>, AccountId) {
+    setup_with(|builder| builder)
+}
+
+fn setup_with(
+    configure: impl FnOnce(RwaClientBuilder) -> RwaClientBuilder,
+) -> (RwaClient, Arc<MockTransport>, Arc<dyn Signer>, AccountId) {
+    let transport = Arc::new(MockTransport::new());
+    let builder = RwaClient::builder()
+        .chain_id(CHAIN_ID)
+        .token_address(&contract_address(1))
+        .identity_address(&contract_address(2))
+        .compliance_address(&contract_address(3))
+        .gas_prices(GAS_PRICES)
+        .gas_config(GasConfig {
+            floor: 0,
+            ceiling: 1_000_000,
+        })
+        .transport(transport.clone());
+    let client = configure(builder).build().unwrap();
+
+    let signer: Arc<dyn Signer> = Arc::new(LocalSigner::random());
+    let sender = signer.account_id("cosmos").unwrap();
+    transport.set_account(&sender, 7, 3);
+    transport.set_simulated_gas(100_000);
+    set_token_paused(&transport, false);
+
+    (client, transport, signer, sender)
+}
+
+fn transfer_request(
+    signer: Arc<dyn Signer>,
+    sender: &AccountId,
+    gas: GasSetting,
+) -> TransferMessageRequest {
+    TransferMessageRequest {
+        from: sender.clone().into(),
+        to: contract_address(9).parse().unwrap(),
+        amount: 100,
+        signer,
+        gas_limit: gas,
+    }
+}
+
+#[tokio::test]
+async fn test_transfer_with_simulated_gas() {
+    let (client, transport, signer, sender) = setup();
+
+    let response = client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Auto { multiplier: 1.5 },
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.gas_wanted, 150_000);
+
+    let txs = transport.broadcast_txs().unwrap();
+    assert_eq!(txs.len(), 1);
+    let tx = &txs[0];
+    assert_eq!(tx.auth_info.fee.gas_limit, 150_000);
+    assert_eq!(tx.auth_info.fee.amount[0].amount, 3_750);
+    assert_eq!(tx.auth_info.fee.amount[0].denom.as_ref(), "ukii");
+    assert_eq!(tx.auth_info.signer_infos[0].sequence, 3);
+
+    let msg: MsgExecuteContract = tx.body.messages[0].to_msg().unwrap();
+    assert_eq!(msg.sender, sender.to_string());
+    assert_eq!(msg.contract, contract_address(1));
+    let transfer: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        transfer,
+        cw20::Cw20ExecuteMsg::Transfer {
+            recipient: contract_address(9),
+            amount: 100u128.into(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_with_fixed_gas_skips_simulation() {
+    let (client, transport, signer, sender) = setup();
+
+    client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap();
+
+    assert!(transport
+        .queries()
+        .iter()
+        .all(|(path, _)| path != "/cosmos.tx.v1beta1.Service/Simulate"));
+    assert_eq!(
+        transport.broadcast_txs().unwrap()[0]
+            .auth_info
+            .fee
+            .gas_limit,
+        80_000
+    );
+}
+
+fn set_min_gas_prices(transport: &MockTransport, minimum_gas_price: &str) {
+    let response = ConfigResponse {
+        minimum_gas_price: minimum_gas_price.to_string(),
+        ..Default::default()
+    };
+    let value = response.encode_to_vec();
+    transport.on_query(CONFIG_PATH, move |_| MockTransport::query_ok(value.clone()));
+}
+
+#[tokio::test]
+async fn test_fee_uses_node_minimum_gas_price() {
+    let (client, transport, signer, sender) =
+        setup_with(|builder| builder.query_min_gas_price(true));
+    set_min_gas_prices(&transport, "0.2uusdc,1.5uatom");
+
+    client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap();
+
+    let fee = &transport.broadcast_txs().unwrap()[0].auth_info.fee;
+    assert_eq!(fee.amount[0].denom.as_ref(), "uusdc");
+    assert_eq!(fee.amount[0].amount, 16_000);
+}
+
+#[tokio::test]
+async fn test_fee_without_accepted_denom_is_rejected() {
+    let (client, transport, signer, sender) =
+        setup_with(|builder| builder.query_min_gas_price(true));
+    set_min_gas_prices(&transport, "1.5uatom");
+
+    let err = client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::Config(_)));
+    assert!(transport.broadcasts().is_empty());
+}
+
+#[tokio::test]
+async fn test_min_gas_prices_reports_node_failures() {
+    let (client, transport, _, _) = setup();
+    transport.push_query_response(
+        CONFIG_PATH,
+        MockTransport::query_error(1, "sdk", "node config unavailable"),
+    );
+
+    assert!(matches!(
+        client.min_gas_prices().await,
+        Err(RwaError::Query { ref path, ref log, .. })
+            if path == CONFIG_PATH && log == "node config unavailable"
+    ));
+
+    set_min_gas_prices(&transport, "cheap");
+    assert!(matches!(
+        client.min_gas_prices().await,
+        Err(RwaError::Encoding(ref reason)) if reason.contains("cheap")
+    ));
+}
+
+#[tokio::test]
+async fn test_failed_delivery_reports_contract_error() {
+    let (client, transport, signer, sender) = setup();
+    transport.push_broadcast_response(MockTransport::broadcast_response(
+        0,
+        5,
+        "wasm",
+        "failed to execute message; message index: 0: Unauthorized: execute wasm contract failed",
+    ));
+
+    let err = client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap_err();
+
+    let RwaError::DeliverTx(failure) = err else {
+        panic!("expected a DeliverTx failure, got {:?}", err);
+    };
+    assert_eq!(failure.code, 5);
+    assert_eq!(failure.codespace, "wasm");
+    assert_eq!(failure.contract_error.as_deref(), Some("Unauthorized"));
+    assert!(!failure.tx_hash.is_empty());
+}
+
+#[tokio::test]
+async fn test_rejected_transaction_reports_check_tx() {
+    let (client, transport, signer, sender) = setup();
+    transport.push_broadcast_response(MockTransport::broadcast_response(
+        13,
+        0,
+        "sdk",
+        "insufficient fee",
+    ));
+
+    let err = client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::CheckTx(ref failure) if failure.code == 13));
+}
+
+#[tokio::test]
+async fn test_failed_simulation_is_not_broadcast() {
+    let (client, transport, signer, sender) = setup();
+    transport.push_query_response(
+        "/cosmos.tx.v1beta1.Service/Simulate",
+        MockTransport::query_error(
+            5,
+            "wasm",
+            "failed to execute message; message index: 0: Paused: execute wasm contract failed \
+             With gas wanted: '0' and gas used: '51234' ",
+        ),
+    );
+
+    let err = client
+        .transfer(transfer_request(signer, &sender, GasSetting::default()))
+        .await
+        .unwrap_err();
+
+    let RwaError::Simulation(failure) = err else {
+        panic!("expected a simulation failure, got {:?}", err);
+    };
+    assert_eq!(failure.contract_error.as_deref(), Some("Paused"));
+    assert!(transport.broadcasts().is_empty());
+}
+
+/// Answers the balance and freeze queries of the token contract.
+fn set_token_balance(transport: &MockTransport, balance: u128, address_frozen: bool, frozen: u128) {
+    transport.on_contract_query(&contract_address(1), move |query| {
+        let query: serde_json::Value = serde_json::from_slice(query).unwrap();
+        let response = if query.get("balance").is_some() {
+            serde_json::to_vec(&cw20::BalanceResponse {
+                balance: balance.into(),
+            })
+        } else if query.get("is_frozen").is_some() {
+            serde_json::to_vec(&address_frozen)
+        } else {
+            assert_eq!(query["frozen_tokens"]["address"], contract_address(9));
+            serde_json::to_vec(&cosmwasm_std::Uint128::from(frozen))
+        };
+        Ok(response.unwrap())
+    });
+}
+
+#[tokio::test]
+async fn test_balance_query() {
+    let (client, transport, _, _) = setup();
+    set_token_balance(&transport, 42, false, 0);
+
+    let balance = client
+        .balance(TokenInfoRequest {
+            address: contract_address(9).parse().unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(balance.balance.u128(), 42);
+    assert_eq!(balance.free.u128(), 42);
+}
+
+#[tokio::test]
+async fn test_balance_reports_frozen_tokens() {
+    let (client, transport, _, _) = setup();
+    let request = || TokenInfoRequest {
+        address: contract_address(9).parse().unwrap(),
+    };
+
+    set_token_balance(&transport, 42, false, 30);
+    let balance = client.balance(request()).await.unwrap();
+    assert_eq!(
+        (
+            balance.balance.u128(),
+            balance.frozen.u128(),
+            balance.free.u128()
+        ),
+        (42, 30, 12)
+    );
+    assert_eq!(client.frozen_tokens(request()).await.unwrap(), 30);
+    assert!(!client.is_frozen(request()).await.unwrap());
+
+    set_token_balance(&transport, 42, true, 30);
+    let balance = client.balance(request()).await.unwrap();
+    assert_eq!((balance.frozen.u128(), balance.free.u128()), (42, 0));
+    assert!(client.is_frozen(request()).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_balance_of_token_without_freezes() {
+    let (client, transport, _, _) = setup();
+    transport.on_contract_query(&contract_address(1), |query| {
+        let query: serde_json::Value = serde_json::from_slice(query).unwrap();
+        if query.get("balance").is_none() {
+            return Err(
+                "Error parsing into type cw20_base::msg::QueryMsg: unknown variant `is_frozen`"
+                    .to_string(),
+            );
+        }
+        Ok(serde_json::to_vec(&cw20::BalanceResponse {
+            balance: 42u128.into(),
+        })
+        .unwrap())
+    });
+
+    let balance = client
+        .balance(TokenInfoRequest {
+            address: contract_address(9).parse().unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        (
+            balance.balance.u128(),
+            balance.frozen.u128(),
+            balance.free.u128()
+        ),
+        (42, 0, 42)
+    );
+}
+
+#[tokio::test]
+async fn test_balance_reports_failing_freeze_query() {
+    let (client, transport, _, _) = setup();
+    transport.on_contract_query(&contract_address(1), |query| {
+        let query: serde_json::Value = serde_json::from_slice(query).unwrap();
+        if query.get("balance").is_none() {
+            return Err("out of gas in location: wasm contract".to_string());
+        }
+        Ok(serde_json::to_vec(&cw20::BalanceResponse {
+            balance: 42u128.into(),
+        })
+        .unwrap())
+    });
+
+    let err = client
+        .balance(TokenInfoRequest {
+            address: contract_address(9).parse().unwrap(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::Contract(ref log) if log.contains("out of gas")));
+}
+
+#[tokio::test]
+async fn test_query_contract_error() {
+    let (client, transport, _, _) = setup();
+    transport.on_contract_query(&contract_address(1), |_| Err("not found".to_string()));
+
+    let err = client.coin_info().await.unwrap_err();
+    assert!(matches!(err, RwaError::Contract(_)));
+}
+
+#[tokio::test]
+async fn test_sync_broadcast_returns_before_inclusion() {
+    let (client, transport, signer, sender) = setup_with(|builder| {
+        builder
+            .broadcast_mode(BroadcastMode::Sync)
+            .tx_poll_interval(Duration::from_millis(1))
+    });
+    transport.delay_tx_inclusion(2);
+
+    let pending = client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap();
+    assert!(!pending.is_committed());
+    assert_eq!(pending.height, 0);
+
+    let committed = client
+        .wait_for_tx(&pending.tx_hash, Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert!(committed.is_committed());
+    assert_eq!(committed.tx_hash, pending.tx_hash);
+    assert_eq!(committed.gas_wanted, 80_000);
+}
+
+#[tokio::test]
+async fn test_sync_broadcast_reports_check_tx() {
+    let (client, transport, signer, sender) =
+        setup_with(|builder| builder.broadcast_mode(BroadcastMode::Sync));
+    transport.push_broadcast_response(MockTransport::broadcast_response(
+        13,
+        0,
+        "sdk",
+        "insufficient fee",
+    ));
+
+    let err = client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::CheckTx(ref failure) if failure.code == 13));
+}
+
+#[tokio::test]
+async fn test_wait_for_failed_tx_reports_deliver_tx() {
+    let (client, transport, signer, sender) =
+        setup_with(|builder| builder.broadcast_mode(BroadcastMode::Async));
+    transport.push_broadcast_response(MockTransport::broadcast_response(
+        0,
+        5,
+        "wasm",
+        "failed to execute message; message index: 0: Unauthorized: execute wasm contract failed",
+    ));
+
+    let pending = client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap();
+    let err = client
+        .wait_for_tx(&pending.tx_hash, Duration::from_secs(5))
+        .await
+        .unwrap_err();
+
+    let RwaError::DeliverTx(failure) = err else {
+        panic!("expected a DeliverTx failure, got {:?}", err);
+    };
+    assert_eq!(failure.contract_error.as_deref(), Some("Unauthorized"));
+    assert!(failure.height > 0);
+}
+
+#[tokio::test]
+async fn test_wait_for_unknown_tx_times_out() {
+    let (client, _, _, _) =
+        setup_with(|builder| builder.tx_poll_interval(Duration::from_millis(5)));
+    let tx_hash = "A".repeat(64);
+
+    let err = client
+        .wait_for_tx(&tx_hash, Duration::from_millis(20))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::TxTimeout { tx_hash: ref hash, .. } if *hash == tx_hash));
+}
+
+#[tokio::test]
+async fn test_wait_for_tx_reports_transport_failure() {
+    let (client, transport, _, _) =
+        setup_with(|builder| builder.tx_poll_interval(Duration::from_millis(5)));
+    transport.fail_tx_lookups("connection refused");
+
+    let started = std::time::Instant::now();
+    let err = client
+        .wait_for_tx(&"A".repeat(64), Duration::from_secs(5))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::Transport(_)));
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+fn sequences(transport: &MockTransport) -> Vec<u64> {
+    transport
+        .broadcast_txs()
+        .unwrap()
+        .iter()
+        .map(|tx| tx.auth_info.signer_infos[0].sequence)
+        .collect()
+}
+
+fn account_queries(transport: &MockTransport) -> usize {
+    transport
+        .queries()
+        .iter()
+        .filter(|(path, _)| path.starts_with("/cosmos.auth."))
+        .count()
+}
+
+#[tokio::test]
+async fn test_concurrent_transfers_use_consecutive_sequences() {
+    let (client, transport, _, _) = setup();
+    let signer: Arc<dyn Signer> = Arc::new(LocalSigner::random());
+    let sender = signer.account_id("cosmos").unwrap();
+    transport.set_account(&sender, 7, 3);
+
+    let (first, second) = tokio::join!(
+        client.transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::default()
+        )),
+        client.transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::default()
+        )),
+    );
+    first.unwrap();
+    second.unwrap();
+
+    let mut sequences = sequences(&transport);
+    sequences.sort();
+    assert_eq!(sequences, vec![3, 4]);
+    assert_eq!(account_queries(&transport), 1);
+}
+
+#[tokio::test]
+async fn test_sequence_mismatch_is_retried_with_expected_sequence() {
+    let (client, transport, signer, sender) = setup();
+    transport.push_broadcast_response(MockTransport::broadcast_response(
+        32,
+        0,
+        "sdk",
+        "account sequence mismatch, expected 5, got 3: incorrect account sequence",
+    ));
+
+    client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap();
+
+    assert_eq!(sequences(&transport), vec![3, 5]);
+}
+
+#[tokio::test]
+async fn test_sequence_mismatch_resyncs_from_chain() {
+    let (client, transport, signer, sender) = setup();
+
+    client
+        .transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::Fixed(80_000),
+        ))
+        .await
+        .unwrap();
+
+    // The key signed transactions elsewhere in the meantime
+    transport.set_account(&sender, 7, 10);
+    transport.push_broadcast_response(MockTransport::broadcast_response(
+        32,
+        0,
+        "sdk",
+        "incorrect account sequence",
+    ));
+    client
+        .transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::Fixed(80_000),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(sequences(&transport), vec![3, 4, 10]);
+    assert_eq!(account_queries(&transport), 2);
+}
+
+/// A transport holding every broadcast until two broadcasts are in flight.
+#[derive(Debug)]
+struct PairedBroadcasts {
+    transport: Arc<MockTransport>,
+    barrier: tokio::sync::Barrier,
+}
+
+#[async_trait]
+impl Transport for PairedBroadcasts {
+    async fn abci_query(&self, path: &str, data: Vec<u8>) -> Result<AbciQuery, RwaError> {
+        self.transport.abci_query(path, data).await
+    }
+
+    async fn abci_query_at(
+        &self,
+        path: &str,
+        data: Vec<u8>,
+        height: u64,
+    ) -> Result<AbciQuery, RwaError> {
+        self.transport.abci_query_at(path, data, height).await
+    }
+
+    async fn broadcast_tx_commit(
+        &self,
+        tx_bytes: Vec<u8>,
+    ) -> Result<tx_commit::Response, RwaError> {
+        self.barrier.wait().await;
+        self.transport.broadcast_tx_commit(tx_bytes).await
+    }
+
+    async fn broadcast_tx_sync(&self, tx_bytes: Vec<u8>) -> Result<tx_sync::Response, RwaError> {
+        self.transport.broadcast_tx_sync(tx_bytes).await
+    }
+
+    async fn broadcast_tx_async(&self, tx_bytes: Vec<u8>) -> Result<tx_async::Response, RwaError> {
+        self.transport.broadcast_tx_async(tx_bytes).await
+    }
+
+    async fn tx(&self, hash: Hash) -> Result<tx::Response, RwaError> {
+        self.transport.tx(hash).await
+    }
+}
+
+#[tokio::test]
+async fn test_transfers_of_one_sender_are_broadcast_concurrently() {
+    let (_, transport, signer, sender) = setup();
+    let client = RwaClient::builder()
+        .chain_id(CHAIN_ID)
+        .token_address(&contract_address(1))
+        .identity_address(&contract_address(2))
+        .compliance_address(&contract_address(3))
+        .gas_prices(GAS_PRICES)
+        .transport(Arc::new(PairedBroadcasts {
+            transport: transport.clone(),
+            barrier: tokio::sync::Barrier::new(2),
+        }))
+        .build()
+        .unwrap();
+
+    // Each broadcast only completes once the other one started
+    let (first, second) = tokio::time::timeout(Duration::from_secs(5), async {
+        tokio::join!(
+            client.transfer(transfer_request(
+                signer.clone(),
+                &sender,
+                GasSetting::Fixed(80_000)
+            )),
+            client.transfer(transfer_request(
+                signer.clone(),
+                &sender,
+                GasSetting::Fixed(80_000)
+            )),
+        )
+    })
+    .await
+    .expect("broadcasts of one sender were serialized");
+    first.unwrap();
+    second.unwrap();
+
+    let mut sequences = sequences(&transport);
+    sequences.sort();
+    assert_eq!(sequences, vec![3, 4]);
+}
+
+#[tokio::test]
+async fn test_sequence_of_rejected_transaction_is_reused() {
+    let (client, transport, signer, sender) = setup();
+    transport.push_broadcast_response(MockTransport::broadcast_response(
+        13,
+        0,
+        "sdk",
+        "insufficient fee",
+    ));
+
+    let err = client
+        .transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::Fixed(80_000),
+        ))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::CheckTx(_)));
+
+    client
+        .transfer(transfer_request(signer, &sender, GasSetting::Fixed(80_000)))
+        .await
+        .unwrap();
+
+    assert_eq!(sequences(&transport), vec![3, 3]);
+    assert_eq!(account_queries(&transport), 1);
+}
+
+#[tokio::test]
+async fn test_batch_signs_one_transaction() {
+    let (client, transport, signer, sender) = setup();
+    transport.on_contract_execute(&contract_address(1), |_| Ok(b"transferred".to_vec()));
+
+    let response = client
+        .batch(&Address::from(&sender))
+        .add_identity("US")
+        .add_claim(
+            Claim {
+                topic: 1u128.into(),
+                issuer: sender.to_string(),
+                data: Default::default(),
+                uri: "ipfs://kyc".to_string(),
+            },
+            &address_of(9),
+        )
+        .transfer(&address_of(9), 100)
+        .execute(signer.as_ref(), GasSetting::Fixed(300_000))
+        .await
+        .unwrap();
+
+    let txs = transport.broadcast_txs().unwrap();
+    assert_eq!(txs.len(), 1);
+    assert_eq!(txs[0].body.messages.len(), 3);
+    assert_eq!(txs[0].auth_info.fee.amount.len(), 1);
+    let contracts: Vec<String> = txs[0]
+        .body
+        .messages
+        .iter()
+        .map(|msg| msg.to_msg::<MsgExecuteContract>().unwrap().contract)
+        .collect();
+    assert_eq!(
+        contracts,
+        vec![
+            contract_address(2),
+            contract_address(2),
+            contract_address(1)
+        ]
+    );
+
+    assert!(response.tx.is_committed());
+    assert_eq!(response.messages.len(), 3);
+    assert!(response.messages[0].data.is_empty());
+    assert_eq!(response.messages[2].data, b"transferred");
+    for (index, message) in response.messages.iter().enumerate() {
+        assert_eq!(message.events.len(), 1);
+        let msg_index = message.events[0]
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key_bytes() == b"msg_index")
+            .unwrap();
+        assert_eq!(msg_index.value_str().unwrap(), index.to_string());
+    }
+}
+
+#[tokio::test]
+async fn test_failing_batch_message_fails_transaction() {
+    let (client, transport, signer, sender) = setup();
+    transport.on_contract_execute(&contract_address(2), |_| Err("Unauthorized".to_string()));
+
+    let err = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .remove_identity(&address_of(9))
+        .execute(signer.as_ref(), GasSetting::Fixed(300_000))
+        .await
+        .unwrap_err();
+
+    let RwaError::DeliverTx(failure) = err else {
+        panic!("expected a DeliverTx failure, got {:?}", err);
+    };
+    assert_eq!(failure.contract_error.as_deref(), Some("Unauthorized"));
+    assert!(failure.log.contains("message index: 1"));
+}
+
+#[tokio::test]
+async fn test_empty_batch_is_rejected() {
+    let (client, transport, signer, sender) = setup();
+
+    let err = client
+        .batch(&Address::from(&sender))
+        .execute(signer.as_ref(), GasSetting::default())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::Config(_)));
+    assert!(transport.broadcasts().is_empty());
+}
+
+#[tokio::test]
+async fn test_offline_signed_transaction_is_broadcast() {
+    let (client, transport, signer, sender) = setup();
+
+    let unsigned = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+    assert!(transport.broadcasts().is_empty());
+
+    // The transaction travels to the offline signer as JSON
+    let json = serde_json::to_string(&unsigned).unwrap();
+    let unsigned: UnsignedTx = serde_json::from_str(&json).unwrap();
+    assert_eq!(unsigned.chain_id, CHAIN_ID);
+    assert_eq!(unsigned.account_number, 7);
+    assert_eq!(unsigned.sequence, 3);
+    assert_eq!(unsigned.body().unwrap().messages.len(), 1);
+    assert_eq!(unsigned.auth_info().unwrap().fee.gas_limit, 200_000);
+
+    let tx_bytes = unsigned.sign(signer.as_ref()).await.unwrap();
+    let response = client.broadcast_signed(&tx_bytes).await.unwrap();
+
+    assert!(response.is_committed());
+    assert_eq!(sequences(&transport), [3]);
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    let msg: MsgExecuteContract = tx.body.messages[0].to_msg().unwrap();
+    assert_eq!(msg.sender, sender.to_string());
+    assert_eq!(
+        tx.signatures[0],
+        signer.sign(&unsigned.sign_bytes().unwrap()).await.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_offline_transaction_rejects_other_signer() {
+    let (client, _, signer, sender) = setup();
+
+    let unsigned = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let err = unsigned.sign(&LocalSigner::random()).await.unwrap_err();
+    assert!(matches!(err, RwaError::Signing(_)));
+}
+
+#[tokio::test]
+async fn test_offline_transaction_of_eth_secp256k1_signer() {
+    let (client, transport, _, _) = setup();
+    let signer = LocalSigner::from_slice_with_key_type(&[5; 32], KeyType::EthSecp256k1).unwrap();
+    let sender = signer.account_id("cosmos").unwrap();
+    transport.set_account(&sender, 9, 0);
+
+    let unsigned = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .key_type(KeyType::EthSecp256k1)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let public_key = unsigned.auth_info().unwrap().signer_infos[0]
+        .public_key
+        .clone()
+        .unwrap();
+    assert_eq!(public_key.type_url(), ETH_SECP256K1_TYPE_URL);
+    let cosmos_signer = LocalSigner::from_slice(&[5; 32]).unwrap();
+    assert!(matches!(
+        unsigned.sign(&cosmos_signer).await,
+        Err(RwaError::Signing(_))
+    ));
+
+    let tx_bytes = unsigned.sign(&signer).await.unwrap();
+    client.broadcast_signed(&tx_bytes).await.unwrap();
+    assert_eq!(
+        transport.broadcast_txs().unwrap()[0].signatures[0].len(),
+        65
+    );
+}
+
+#[tokio::test]
+async fn test_amino_json_sign_doc() {
+    let (client, _, signer, sender) = setup();
+
+    let unsigned = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .sign_mode(SignMode::AminoJson)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    assert_eq!(unsigned.sign_mode().unwrap(), SignMode::AminoJson);
+    let expected = format!(
+        concat!(
+            r#"{{"account_number":"7","chain_id":"{}","#,
+            r#""fee":{{"amount":[{{"amount":"5000","denom":"ukii"}}],"gas":"200000"}},"#,
+            r#""memo":"","msgs":[{{"type":"wasm/MsgExecuteContract","value":{{"contract":"{}","#,
+            r#""funds":[],"msg":{{"transfer":{{"amount":"100","recipient":"{}"}}}},"sender":"{}"}}}}],"#,
+            r#""sequence":"3"}}"#
+        ),
+        CHAIN_ID,
+        contract_address(1),
+        contract_address(9),
+        sender
+    );
+    assert_eq!(
+        String::from_utf8(unsigned.sign_bytes().unwrap()).unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_amino_json_signer_sets_sign_mode() {
+    let (client, transport, _, _) = setup();
+    let signer = Arc::new(LocalSigner::random().with_sign_mode(SignMode::AminoJson));
+    let sender = signer.account_id("cosmos").unwrap();
+    transport.set_account(&sender, 8, 0);
+
+    client
+        .transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    assert_eq!(
+        tx.auth_info.signer_infos[0].mode_info,
+        ModeInfo::single(ProtoSignMode::LegacyAminoJson)
+    );
+    let sign_doc = String::from_utf8(
+        UnsignedTx {
+            chain_id: CHAIN_ID.to_string(),
+            account_number: 8,
+            sequence: 0,
+            body_bytes: tx.body.clone().into_bytes().unwrap().into(),
+            auth_info_bytes: tx.auth_info.clone().into_bytes().unwrap().into(),
+        }
+        .sign_bytes()
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(sign_doc.contains(r#""type":"wasm/MsgExecuteContract""#));
+    assert_eq!(
+        tx.signatures[0],
+        signer.sign(sign_doc.as_bytes()).await.unwrap()
+    );
+}
+
+/// A 2-of-3 multisig account registered on the mock chain, with its member keys.
+fn multisig_setup(transport: &MockTransport) -> (MultisigAccount, Vec<LocalSigner>, AccountId) {
+    let members: Vec<LocalSigner> = (1..=3)
+        .map(|key| LocalSigner::from_slice(&[key; 32]).unwrap())
+        .collect();
+    let multisig = MultisigAccount::new(
+        2,
+        members.iter().map(|member| member.public_key()).collect(),
+    )
+    .unwrap();
+    let address = multisig.account_id("cosmos").unwrap();
+    transport.set_account(&address, 9, 4);
+    (multisig, members, address)
+}
+
+#[tokio::test]
+async fn test_multisig_batch_signed_in_process() {
+    let (client, transport, _, _) = setup();
+    let (multisig, members, address) = multisig_setup(&transport);
+
+    client
+        .batch(&Address::from(&address))
+        .transfer(&address_of(9), 100)
+        .execute_multisig(
+            &multisig,
+            &[&members[0], &members[2]],
+            GasSetting::Auto { multiplier: 1.0 },
+        )
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    let signer_info = &tx.auth_info.signer_infos[0];
+    assert_eq!(signer_info.sequence, 4);
+    let key = signer_info.public_key.as_ref().unwrap();
+    assert_eq!(key.legacy_amino_multisig().unwrap().threshold, 2);
+    let ModeInfo::Multi(multi) = &signer_info.mode_info else {
+        panic!("expected a multisig mode info");
+    };
+    assert_eq!(multi.mode_infos.len(), 2);
+
+    let signature = MultiSignature::decode(tx.signatures[0].as_slice()).unwrap();
+    assert_eq!(signature.signatures.len(), 2);
+}
+
+#[tokio::test]
+async fn test_multisig_partial_signatures_are_combined() {
+    let (client, transport, _, _) = setup();
+    let (multisig, members, address) = multisig_setup(&transport);
+
+    let unsigned = client
+        .batch(&Address::from(&address))
+        .transfer(&address_of(9), 100)
+        .build_multisig(&multisig, GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+    assert_eq!(unsigned.sign_mode().unwrap(), SignMode::AminoJson);
+    assert!(matches!(
+        unsigned.sign(&members[0]).await,
+        Err(RwaError::Signing(_))
+    ));
+
+    // Every member signs on its own machine and returns a file
+    let mut partials = Vec::new();
+    for member in &members[1..] {
+        let partial = unsigned.sign_partial(member).await.unwrap();
+        let json = serde_json::to_string(&partial).unwrap();
+        partials.push(serde_json::from_str::<PartialSignature>(&json).unwrap());
+    }
+
+    assert!(matches!(
+        unsigned.combine(&partials[..1]),
+        Err(RwaError::Signing(ref reason)) if reason.contains("1 of the 2")
+    ));
+    let mut forged = partials.clone();
+    forged[0].signature = partials[1].signature.clone();
+    assert!(unsigned.combine(&forged).is_err());
+    assert!(unsigned.sign_partial(&LocalSigner::random()).await.is_err());
+
+    let tx_bytes = unsigned.combine(&partials).unwrap();
+    client.broadcast_signed(&tx_bytes).await.unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    let signature = MultiSignature::decode(tx.signatures[0].as_slice()).unwrap();
+    assert_eq!(
+        signature.signatures,
+        [
+            partials[0].signature.to_vec(),
+            partials[1].signature.to_vec()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_multisig_batch_requires_multisig_sender() {
+    let (client, transport, _, sender) = setup();
+    let (multisig, _, _) = multisig_setup(&transport);
+
+    let err = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .build_multisig(&multisig, GasSetting::Fixed(200_000))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::Config(_)));
+}
+
+#[tokio::test]
+async fn test_authz_grant_of_rwa_contracts() {
+    let (client, transport, signer, sender) = setup();
+    let agent = contract_address(8);
+    let expiration = UNIX_EPOCH + Duration::from_secs(1_900_000_000);
+
+    client
+        .grant(GrantRequest {
+            from: sender.clone().into(),
+            grantee: agent.parse().unwrap(),
+            authorization: client.contract_authorization(ContractLimit::MaxCalls(10)),
+            expiration: Some(expiration),
+            signer,
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    let grant: MsgGrant = tx.body.messages[0].to_msg().unwrap();
+    assert_eq!(grant.granter, sender.to_string());
+    assert_eq!(grant.grantee, agent);
+    let grant = grant.grant.unwrap();
+    assert_eq!(grant.expiration.unwrap().seconds, 1_900_000_000);
+    let authorization: ContractExecutionAuthorization =
+        grant.authorization.unwrap().to_msg().unwrap();
+    let contracts: Vec<String> = authorization
+        .grants
+        .iter()
+        .map(|grant| grant.contract.clone())
+        .collect();
+    assert_eq!(
+        contracts,
+        vec![
+            contract_address(1),
+            contract_address(2),
+            contract_address(3)
+        ]
+    );
+    let limit: MaxCallsLimit = authorization.grants[0]
+        .limit
+        .as_ref()
+        .unwrap()
+        .to_msg()
+        .unwrap();
+    assert_eq!(limit.remaining, 10);
+}
+
+#[tokio::test]
+async fn test_authz_grant_converts_evm_contract_addresses() {
+    let (client, transport, signer, sender) = setup();
+    let contract = Address::from_bytes([5; 20]);
+    let authorization = Authorization::ContractExecution(vec![ContractGrant::new(
+        &contract.to_hex().unwrap().parse().unwrap(),
+        ContractLimit::MaxCalls(1),
+    )]);
+
+    client
+        .grant(GrantRequest {
+            from: sender.into(),
+            grantee: address_of(8),
+            authorization,
+            expiration: None,
+            signer,
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    let grant: MsgGrant = tx.body.messages[0].to_msg().unwrap();
+    let authorization: ContractExecutionAuthorization = grant
+        .grant
+        .unwrap()
+        .authorization
+        .unwrap()
+        .to_msg()
+        .unwrap();
+    assert_eq!(
+        authorization.grants[0].contract,
+        contract.to_bech32("cosmos").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_authz_exec_is_signed_by_grantee() {
+    let (client, transport, _, issuer) = setup();
+    let client = client.with_authz_exec(true);
+    let agent: Arc<dyn Signer> = Arc::new(LocalSigner::random());
+    let agent_address = agent.account_id("cosmos").unwrap();
+    transport.set_account(&agent_address, 11, 5);
+
+    client
+        .transfer(transfer_request(
+            agent.clone(),
+            &issuer,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    assert_eq!(tx.body.messages.len(), 1);
+    let exec: MsgExec = tx.body.messages[0].to_msg().unwrap();
+    assert_eq!(exec.grantee, agent_address.to_string());
+    let inner: MsgExecuteContract = exec.msgs[0].to_msg().unwrap();
+    assert_eq!(inner.sender, issuer.to_string());
+    assert_eq!(inner.contract, contract_address(1));
+    assert_eq!(
+        tx.auth_info.signer_infos[0].public_key,
+        Some(agent.public_key().into())
+    );
+    assert_eq!(tx.auth_info.signer_infos[0].sequence, 5);
+}
+
+#[tokio::test]
+async fn test_authz_exec_batch_returns_result_per_message() {
+    let (client, transport, _, issuer) = setup();
+    let client = client.with_authz_exec(true);
+    let agent = LocalSigner::random();
+    transport.set_account(&agent.account_id("cosmos").unwrap(), 11, 5);
+    transport.on_contract_execute(&contract_address(1), |_| Ok(b"transferred".to_vec()));
+
+    let response = client
+        .batch(&Address::from(&issuer))
+        .add_identity("US")
+        .transfer(&address_of(9), 100)
+        .transfer(&address_of(8), 50)
+        .execute(&agent, GasSetting::Fixed(300_000))
+        .await
+        .unwrap();
+
+    assert_eq!(transport.broadcast_txs().unwrap()[0].body.messages.len(), 1);
+    assert_eq!(response.messages.len(), 3);
+    assert!(response.messages[0].data.is_empty());
+    assert_eq!(response.messages[1].data, b"transferred");
+    assert_eq!(response.messages[2].data, b"transferred");
+    let contracts: Vec<String> = response
+        .messages
+        .iter()
+        .map(|message| {
+            assert_eq!(message.events.len(), 1);
+            message.events[0].attributes[0]
+                .value_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(
+        contracts,
+        [
+            contract_address(2),
+            contract_address(1),
+            contract_address(1)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_authz_exec_leaves_own_operations_unwrapped() {
+    let (client, transport, signer, sender) = setup();
+    let client = client.with_authz_exec(true);
+
+    client
+        .batch(&Address::from(&sender))
+        .revoke(&address_of(8))
+        .transfer(&address_of(9), 100)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    assert_eq!(tx.body.messages.len(), 2);
+    let revoke: MsgRevoke = tx.body.messages[0].to_msg().unwrap();
+    assert_eq!(revoke.granter, sender.to_string());
+    assert_eq!(revoke.msg_type_url, "/cosmwasm.wasm.v1.MsgExecuteContract");
+    tx.body.messages[1].to_msg::<MsgExecuteContract>().unwrap();
+}
+
+#[tokio::test]
+async fn test_authz_exec_amino_json_sign_doc() {
+    let (client, transport, _, issuer) = setup();
+    let client = client.with_authz_exec(true);
+    let agent = LocalSigner::random();
+    let agent_address = agent.account_id("cosmos").unwrap();
+    transport.set_account(&agent_address, 11, 5);
+
+    let unsigned = client
+        .batch(&Address::from(&issuer))
+        .transfer(&address_of(9), 100)
+        .sign_mode(SignMode::AminoJson)
+        .build_unsigned(agent.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let sign_doc = String::from_utf8(unsigned.sign_bytes().unwrap()).unwrap();
+    let expected = format!(
+        concat!(
+            r#""msgs":[{{"type":"cosmos-sdk/MsgExec","value":{{"grantee":"{}","#,
+            r#""msgs":[{{"type":"wasm/MsgExecuteContract","value":{{"contract":"{}","#
+        ),
+        agent_address,
+        contract_address(1)
+    );
+    assert!(sign_doc.contains(&expected), "{}", sign_doc);
+    assert_eq!(unsigned.account_number, 11);
+}
+
+#[tokio::test]
+async fn test_authz_grant_amino_json_sign_doc() {
+    let (client, _, signer, sender) = setup();
+    let authorization = Authorization::ContractExecution(vec![ContractGrant::new(
+        &address_of(1),
+        ContractLimit::Combined {
+            calls: 10,
+            funds: vec![Coin::new(5, "ukii").unwrap()],
+        },
+    )
+    .messages(&["transfer"])]);
+
+    let unsigned = client
+        .batch(&Address::from(&sender))
+        .grant(
+            &address_of(8),
+            &Authorization::Generic,
+            Some(UNIX_EPOCH + Duration::from_millis(1_700_000_000_500)),
+        )
+        .grant(&address_of(9), &authorization, None)
+        .sign_mode(SignMode::AminoJson)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let expected = format!(
+        concat!(
+            r#"{{"account_number":"7","chain_id":"{}","#,
+            r#""fee":{{"amount":[{{"amount":"5000","denom":"ukii"}}],"gas":"200000"}},"#,
+            r#""memo":"","msgs":[{{"type":"cosmos-sdk/MsgGrant","value":{{"grant":{{"#,
+            r#""authorization":{{"type":"cosmos-sdk/GenericAuthorization","#,
+            r#""value":{{"msg":"/cosmwasm.wasm.v1.MsgExecuteContract"}}}},"#,
+            r#""expiration":"2023-11-14T22:13:20.5Z"}},"grantee":"{}","granter":"{}"}}}},"#,
+            r#"{{"type":"cosmos-sdk/MsgGrant","value":{{"grant":{{"#,
+            r#""authorization":{{"type":"wasm/ContractExecutionAuthorization","#,
+            r#""value":{{"grants":[{{"contract":"{}","#,
+            r#""filter":{{"type":"wasm/AcceptedMessageKeysFilter","value":{{"keys":["transfer"]}}}},"#,
+            r#""limit":{{"type":"wasm/CombinedLimit","value":{{"#,
+            r#""amounts":[{{"amount":"5","denom":"ukii"}}],"calls_remaining":"10"}}}}}}]}}}}}},"#,
+            r#""grantee":"{}","granter":"{}"}}}}],"sequence":"3"}}"#
+        ),
+        CHAIN_ID,
+        contract_address(8),
+        sender,
+        contract_address(1),
+        contract_address(9),
+        sender
+    );
+    assert_eq!(
+        String::from_utf8(unsigned.sign_bytes().unwrap()).unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_fee_allowance_amino_json_sign_doc() {
+    let (client, _, signer, sender) = setup();
+    let periodic = FeeAllowance::Periodic {
+        spend_limit: vec![Coin::new(1_000, "ukii").unwrap()],
+        expiration: Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+        period: Duration::from_secs(86_400),
+        period_spend_limit: vec![Coin::new(100, "ukii").unwrap()],
+    };
+
+    let unsigned = client
+        .batch(&Address::from(&sender))
+        .grant_fee_allowance(&address_of(8), &periodic, true)
+        .grant_fee_allowance(
+            &address_of(9),
+            &FeeAllowance::Basic {
+                spend_limit: vec![],
+                expiration: None,
+            },
+            false,
+        )
+        .revoke_fee_allowance(&address_of(7))
+        .sign_mode(SignMode::AminoJson)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let expected = format!(
+        concat!(
+            r#"{{"account_number":"7","chain_id":"{}","#,
+            r#""fee":{{"amount":[{{"amount":"5000","denom":"ukii"}}],"gas":"200000"}},"#,
+            r#""memo":"","msgs":[{{"type":"cosmos-sdk/MsgGrantAllowance","value":{{"allowance":{{"#,
+            r#""type":"cosmos-sdk/AllowedMsgAllowance","value":{{"allowance":{{"#,
+            r#""type":"cosmos-sdk/PeriodicAllowance","value":{{"basic":{{"#,
+            r#""expiration":"2023-11-14T22:13:20Z","spend_limit":[{{"amount":"1000","denom":"ukii"}}]}},"#,
+            r#""period":"86400000000000","period_can_spend":[{{"amount":"100","denom":"ukii"}}],"#,
+            r#""period_reset":"0001-01-01T00:00:00Z","#,
+            r#""period_spend_limit":[{{"amount":"100","denom":"ukii"}}]}}}},"#,
+            r#""allowed_messages":["/cosmwasm.wasm.v1.MsgExecuteContract"]}}}},"#,
+            r#""grantee":"{}","granter":"{}"}}}},"#,
+            r#"{{"type":"cosmos-sdk/MsgGrantAllowance","value":{{"allowance":{{"#,
+            r#""type":"cosmos-sdk/BasicAllowance","value":{{"spend_limit":[]}}}},"#,
+            r#""grantee":"{}","granter":"{}"}}}},"#,
+            r#"{{"type":"cosmos-sdk/MsgRevokeAllowance","value":{{"grantee":"{}","granter":"{}"}}}}],"#,
+            r#""sequence":"3"}}"#
+        ),
+        CHAIN_ID,
+        contract_address(8),
+        sender,
+        contract_address(9),
+        sender,
+        contract_address(7),
+        sender
+    );
+    assert_eq!(
+        String::from_utf8(unsigned.sign_bytes().unwrap()).unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_fee_granter_pays_for_every_transaction() {
+    let issuer = contract_address(8);
+    let (client, transport, signer, sender) = setup_with(|builder| builder.fee_granter(&issuer));
+
+    client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Auto { multiplier: 1.0 },
+        ))
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    assert_eq!(
+        tx.auth_info.fee.granter.as_ref().unwrap().to_string(),
+        issuer
+    );
+    assert_eq!(tx.auth_info.fee.payer, None);
+}
+
+#[tokio::test]
+async fn test_batch_fee_payer_cosigns() {
+    let (client, transport, signer, sender) = setup();
+    let payer = LocalSigner::random();
+    let payer_address = payer.account_id("cosmos").unwrap();
+    transport.set_account(&payer_address, 12, 6);
+
+    client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .fee_payer(&payer)
+        .execute(signer.as_ref(), GasSetting::Auto { multiplier: 1.0 })
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    assert_eq!(tx.auth_info.fee.payer, Some(payer_address));
+    assert_eq!(tx.auth_info.signer_infos.len(), 2);
+    assert_eq!(
+        tx.auth_info.signer_infos[1].public_key,
+        Some(payer.public_key().into())
+    );
+    assert_eq!(tx.auth_info.signer_infos[1].sequence, 6);
+    assert_eq!(tx.signatures.len(), 2);
+
+    let payer_sign_bytes = UnsignedTx {
+        chain_id: CHAIN_ID.to_string(),
+        account_number: 12,
+        sequence: 6,
+        body_bytes: tx.body.clone().into_bytes().unwrap().into(),
+        auth_info_bytes: tx.auth_info.clone().into_bytes().unwrap().into(),
+    }
+    .sign_bytes()
+    .unwrap();
+    assert_eq!(
+        tx.signatures[1],
+        payer.sign(&payer_sign_bytes).await.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_fee_payer_is_not_supported_offline() {
+    let (client, _, signer, sender) = setup();
+    let payer = LocalSigner::random();
+
+    let err = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .fee_payer(&payer)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::Config(_)));
+}
+
+#[tokio::test]
+async fn test_grant_periodic_fee_allowance_for_contract_calls() {
+    let (client, transport, signer, sender) = setup();
+    let investor = contract_address(9);
+
+    client
+        .grant_fee_allowance(GrantFeeAllowanceRequest {
+            from: sender.clone().into(),
+            grantee: investor.parse().unwrap(),
+            allowance: FeeAllowance::Periodic {
+                spend_limit: vec![Coin::new(1_000_000, "ukii").unwrap()],
+                expiration: None,
+                period: Duration::from_secs(86_400),
+                period_spend_limit: vec![Coin::new(10_000, "ukii").unwrap()],
+            },
+            contract_calls_only: true,
+            signer,
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+
+    let tx = &transport.broadcast_txs().unwrap()[0];
+    let grant: MsgGrantAllowance = tx.body.messages[0].to_msg().unwrap();
+    assert_eq!(grant.granter, sender.to_string());
+    assert_eq!(grant.grantee, investor);
+    let allowed: AllowedMsgAllowance = grant.allowance.unwrap().to_msg().unwrap();
+    assert_eq!(
+        allowed.allowed_messages,
+        vec!["/cosmwasm.wasm.v1.MsgExecuteContract"]
+    );
+    let periodic: PeriodicAllowance = allowed.allowance.unwrap().to_msg().unwrap();
+    assert_eq!(periodic.period.unwrap().seconds, 86_400);
+    assert_eq!(periodic.period_spend_limit[0].amount, "10000");
+    assert_eq!(periodic.basic.unwrap().spend_limit[0].amount, "1000000");
+}
+
+#[tokio::test]
+async fn test_query_fee_allowance() {
+    let (client, transport, _, sender) = setup();
+    let path = "/cosmos.feegrant.v1beta1.Query/Allowance";
+    let allowance = FeeAllowance::Basic {
+        spend_limit: vec![Coin::new(500, "ukii").unwrap()],
+        expiration: Some(UNIX_EPOCH + Duration::from_secs(1_900_000_000)),
+    };
+    let basic = BasicAllowance {
+        spend_limit: vec![Coin::new(500, "ukii").unwrap().into()],
+        expiration: Some((UNIX_EPOCH + Duration::from_secs(1_900_000_000)).into()),
+    };
+    let response = QueryAllowanceResponse {
+        allowance: Some(FeeGrantProto {
+            granter: sender.clone().into(),
+            grantee: contract_address(9).parse().unwrap(),
+            allowance: Some(cosmrs::Any::from_msg(&basic).unwrap()),
+        }),
+    };
+    transport.push_query_response(path, MockTransport::query_ok(response.encode_to_vec()));
+    transport.push_query_response(
+        path,
+        MockTransport::query_error(38, "sdk", "fee-grant not found: not found"),
+    );
+    transport.push_query_response(
+        path,
+        MockTransport::query_error(1, "sdk", "store unavailable"),
+    );
+    transport.push_query_response(path, MockTransport::query_ok(vec![0xff]));
+
+    let request = || FeeAllowanceRequest {
+        granter: sender.clone().into(),
+        grantee: contract_address(9).parse().unwrap(),
+    };
+    let grant = client.fee_allowance(request()).await.unwrap().unwrap();
+    assert_eq!(grant.allowance, allowance);
+    assert!(grant.allowed_messages.is_empty());
+
+    assert_eq!(client.fee_allowance(request()).await.unwrap(), None);
+    assert!(matches!(
+        client.fee_allowance(request()).await,
+        Err(RwaError::Query { ref path, code: 1, ref log, .. })
+            if path == "/cosmos.feegrant.v1beta1.Query/Allowance" && log == "store unavailable"
+    ));
+    assert!(matches!(
+        client.fee_allowance(request()).await,
+        Err(RwaError::Encoding(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_unknown_account_is_not_found() {
+    let (client, _, _, _) = setup();
+    let signer: Arc<dyn Signer> = Arc::new(LocalSigner::random());
+    let sender = signer.account_id("cosmos").unwrap();
+
+    let err = client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, RwaError::AccountNotFound(ref address) if *address == sender.to_string())
+    );
+}
+
+/// Registers `account` as the account of `sender` and returns the sequence a transfer is signed with.
+async fn transfer_sequence(
+    account: cosmrs::Any,
+    configure: fn(RwaClientBuilder) -> RwaClientBuilder,
+) -> Result<u64, RwaError> {
+    let (client, transport, signer, sender) = setup_with(configure);
+    transport.set_account_any(&sender, account);
+
+    client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await?;
+    Ok(transport.broadcast_txs().unwrap()[0].auth_info.signer_infos[0].sequence)
+}
+
+fn base_account(sequence: u64) -> BaseAccount {
+    BaseAccount {
+        address: String::new(),
+        pub_key: None,
+        account_number: 7,
+        sequence,
+    }
+}
+
+#[tokio::test]
+async fn test_vesting_and_eth_accounts_are_decoded() {
+    let vesting = ContinuousVestingAccount {
+        base_vesting_account: Some(BaseVestingAccount {
+            base_account: Some(base_account(21)),
+            ..Default::default()
+        }),
+        start_time: 0,
+    };
+    let account = cosmrs::Any::from_msg(&vesting).unwrap();
+    assert_eq!(
+        transfer_sequence(account, |builder| builder).await.unwrap(),
+        21
+    );
+
+    let eth_account = EthAccount {
+        base_account: Some(base_account(22)),
+        code_hash: "c5d2".to_string(),
+    };
+    let account = cosmrs::Any {
+        type_url: ETH_ACCOUNT_TYPE_URL.to_string(),
+        value: eth_account.encode_to_vec(),
+    };
+    assert_eq!(
+        transfer_sequence(account, |builder| builder).await.unwrap(),
+        22
+    );
+}
+
+#[tokio::test]
+async fn test_custom_account_type_is_registered() {
+    let account = cosmrs::Any {
+        type_url: "/kiichain.auth.v1.CustomAccount".to_string(),
+        value: ModuleAccount {
+            base_account: Some(base_account(23)),
+            name: "custom".to_string(),
+            permissions: Vec::new(),
+        }
+        .encode_to_vec(),
+    };
+
+    let err = transfer_sequence(account.clone(), |builder| builder)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, RwaError::AccountLookup { ref reason, .. } if reason.contains("unsupported account type"))
+    );
+
+    let sequence = transfer_sequence(account, |builder| {
+        builder.account_type(
+            "/kiichain.auth.v1.CustomAccount",
+            |account: ModuleAccount| account.base_account,
+        )
+    })
+    .await
+    .unwrap();
+    assert_eq!(sequence, 23);
+}
+
+#[tokio::test]
+async fn test_account_is_queried_through_auth_grpc_path() {
+    let (client, transport, signer, sender) = setup();
+
+    client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap();
+
+    let queries = transport.queries();
+    let (_, data) = queries
+        .iter()
+        .find(|(path, _)| path == "/cosmos.auth.v1beta1.Query/Account")
+        .unwrap();
+    let request = QueryAccountRequest::decode(data.as_slice()).unwrap();
+    assert_eq!(request.address, sender.to_string());
+}
+
+#[tokio::test]
+async fn test_unknown_account_type_falls_back_to_account_info() {
+    let (client, transport, signer, sender) = setup();
+    transport.set_account_any(
+        &sender,
+        cosmrs::Any {
+            type_url: "/kiichain.auth.v1.CustomAccount".to_string(),
+            value: Vec::new(),
+        },
+    );
+    transport.on_query("/cosmos.auth.v1beta1.Query/AccountInfo", |data| {
+        let request = QueryAccountInfoRequest::decode(data).unwrap();
+        let info = BaseAccount {
+            address: request.address,
+            ..base_account(24)
+        };
+        MockTransport::query_ok(QueryAccountInfoResponse { info: Some(info) }.encode_to_vec())
+    });
+
+    client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        transport.broadcast_txs().unwrap()[0].auth_info.signer_infos[0].sequence,
+        24
+    );
+}
+
+#[tokio::test]
+async fn test_evm_addresses_are_sent_with_chain_prefix() {
+    let (client, transport, signer, sender) = setup();
+    let recipient = Address::from_bytes([9; 20]);
+
+    client
+        .transfer(TransferMessageRequest {
+            from: Address::from(&sender).to_hex().unwrap().parse().unwrap(),
+            to: recipient.to_hex().unwrap().parse().unwrap(),
+            amount: 100,
+            signer,
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    assert_eq!(msg.sender, sender.to_string());
+    let transfer: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        transfer,
+        cw20::Cw20ExecuteMsg::Transfer {
+            recipient: recipient.to_bech32("cosmos").unwrap(),
+            amount: 100u128.into(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_address_of_another_chain_is_rejected() {
+    let (client, transport, signer, sender) = setup();
+    let recipient = Address::from_bytes([9; 20]).to_bech32("kii").unwrap();
+
+    let err = client
+        .transfer(TransferMessageRequest {
+            from: sender.clone().into(),
+            to: recipient.parse().unwrap(),
+            amount: 100,
+            signer,
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::InvalidAddress { ref address, .. } if *address == recipient));
+    assert!(transport.broadcast_txs().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_batch_converts_evm_addresses() {
+    let (client, transport, signer, sender) = setup();
+    let recipient = Address::from_bytes([9; 20]);
+    let sender_hex: Address = Address::from(&sender).to_hex().unwrap().parse().unwrap();
+
+    client
+        .batch(&sender_hex)
+        .transfer(&recipient.to_hex().unwrap().parse().unwrap(), 100)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    assert_eq!(msg.sender, sender.to_string());
+    let transfer: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        transfer,
+        cw20::Cw20ExecuteMsg::Transfer {
+            recipient: recipient.to_bech32("cosmos").unwrap(),
+            amount: 100u128.into(),
+        }
+    );
+
+    let foreign: Address = recipient.to_bech32("kii").unwrap().parse().unwrap();
+    let err = client
+        .batch(&sender_hex)
+        .transfer(&foreign, 100)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::InvalidAddress { .. }));
+    assert_eq!(transport.broadcast_txs().unwrap().len(), 1);
+}
+
+fn transfer_from_request(signer: Arc<dyn Signer>, spender: &AccountId) -> TransferFromRequest {
+    TransferFromRequest {
+        spender: spender.clone().into(),
+        owner: contract_address(7).parse().unwrap(),
+        recipient: contract_address(9).parse().unwrap(),
+        amount: 100,
+        signer,
+        gas_limit: GasSetting::Fixed(200_000),
+    }
+}
+
+/// Answers the allowance queries of the token, which is not paused.
+fn set_allowance(transport: &MockTransport, allowance: cw20::AllowanceResponse) {
+    transport.on_contract_query(&contract_address(1), move |query| {
+        let query: serde_json::Value = serde_json::from_slice(query).unwrap();
+        if query.get("paused").is_some() {
+            return Ok(serde_json::to_vec(&false).unwrap());
+        }
+        assert!(query.get("allowance").is_some());
+        Ok(serde_json::to_vec(&allowance).unwrap())
+    });
+}
+
+#[tokio::test]
+async fn test_transfer_from_is_sent_by_spender() {
+    let (client, transport, signer, spender) = setup();
+    set_allowance(
+        &transport,
+        cw20::AllowanceResponse {
+            allowance: 150u128.into(),
+            expires: cw20::Expiration::AtHeight(10),
+        },
+    );
+    transport.set_height(5);
+
+    client
+        .transfer_from(transfer_from_request(signer, &spender))
+        .await
+        .unwrap();
+
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    assert_eq!(msg.sender, spender.to_string());
+    let transfer: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        transfer,
+        cw20::Cw20ExecuteMsg::TransferFrom {
+            owner: contract_address(7),
+            recipient: contract_address(9),
+            amount: 100u128.into(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_transfer_from_beyond_allowance_is_rejected() {
+    let (client, transport, signer, spender) = setup();
+    set_allowance(
+        &transport,
+        cw20::AllowanceResponse {
+            allowance: 60u128.into(),
+            expires: cw20::Expiration::Never {},
+        },
+    );
+
+    let err = client
+        .transfer_from(transfer_from_request(signer, &spender))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        RwaError::InsufficientAllowance {
+            allowance: 60,
+            required: 100,
+            ..
+        }
+    ));
+    assert!(transport.broadcast_txs().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_transfer_from_with_expired_allowance_is_rejected() {
+    let (client, transport, signer, spender) = setup();
+    set_allowance(
+        &transport,
+        cw20::AllowanceResponse {
+            allowance: 150u128.into(),
+            expires: cw20::Expiration::AtHeight(10),
+        },
+    );
+    transport.set_height(9);
+
+    let err = client
+        .transfer_from(transfer_from_request(signer.clone(), &spender))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, RwaError::AllowanceExpired { spender: ref address, .. } if *address == spender.to_string())
+    );
+
+    set_allowance(
+        &transport,
+        cw20::AllowanceResponse {
+            allowance: 150u128.into(),
+            expires: cw20::Expiration::AtTime(cosmwasm_std::Timestamp::from_seconds(1)),
+        },
+    );
+    let err = client
+        .transfer_from(transfer_from_request(signer, &spender))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::AllowanceExpired { .. }));
+    assert!(transport.broadcast_txs().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_increase_allowance_with_expiration() {
+    let (client, transport, signer, sender) = setup();
+
+    client
+        .increase_allowance(AllowanceChangeRequest {
+            from: sender.clone().into(),
+            spender: contract_address(8).parse().unwrap(),
+            amount: 500,
+            expires: Some(Expiration::AtHeight(1_000)),
+            signer,
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    assert_eq!(msg.sender, sender.to_string());
+    let increase: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        increase,
+        cw20::Cw20ExecuteMsg::IncreaseAllowance {
+            spender: contract_address(8),
+            amount: 500u128.into(),
+            expires: Some(Expiration::AtHeight(1_000)),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_batch_decreases_allowance() {
+    let (client, transport, signer, sender) = setup();
+
+    client
+        .batch(&Address::from(&sender))
+        .decrease_allowance(&address_of(8), 200, None)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    let decrease: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        decrease,
+        cw20::Cw20ExecuteMsg::DecreaseAllowance {
+            spender: contract_address(8),
+            amount: 200u128.into(),
+            expires: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_allowance_queries_are_paginated() {
+    let (client, transport, _, _) = setup();
+    transport.on_contract_query(&contract_address(1), |query| {
+        let query: serde_json::Value = serde_json::from_slice(query).unwrap();
+        let response = if let Some(query) = query.get("all_allowances") {
+            assert_eq!(query["owner"], contract_address(7));
+            assert_eq!(query["start_after"], contract_address(8));
+            assert_eq!(query["limit"], 10);
+            serde_json::json!({
+                "allowances": [{
+                    "spender": contract_address(9),
+                    "allowance": "300",
+                    "expires": { "never": {} },
+                }]
+            })
+        } else {
+            let query = &query["all_spender_allowances"];
+            assert_eq!(query["spender"], contract_address(9));
+            assert!(query["start_after"].is_null());
+            serde_json::json!({
+                "allowances": [{
+                    "owner": contract_address(7),
+                    "allowance": "300",
+                    "expires": { "never": {} },
+                }]
+            })
+        };
+        Ok(serde_json::to_vec(&response).unwrap())
+    });
+
+    let allowances = client
+        .all_allowances(AllAllowancesRequest {
+            owner: contract_address(7).parse().unwrap(),
+            start_after: Some(contract_address(8).parse().unwrap()),
+            limit: Some(10),
+        })
+        .await
+        .unwrap();
+    assert_eq!(allowances.allowances[0].spender, contract_address(9));
+    assert_eq!(allowances.allowances[0].allowance.u128(), 300);
+
+    let allowances = client
+        .all_spender_allowances(AllSpenderAllowancesRequest {
+            spender: contract_address(9).parse().unwrap(),
+            start_after: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(allowances.allowances[0].owner, contract_address(7));
+}
+
+fn mint_request(signer: Arc<dyn Signer>, minter: &AccountId) -> MintRequest {
+    MintRequest {
+        from: minter.clone().into(),
+        recipient: contract_address(9).parse().unwrap(),
+        amount: 1_000,
+        check_compliance: true,
+        signer,
+        gas_limit: GasSetting::Fixed(200_000),
+    }
+}
+
+#[tokio::test]
+async fn test_mint_checks_recipient_compliance() {
+    let (client, transport, signer, minter) = setup();
+    transport.on_contract_query(&contract_address(3), |query| {
+        let query: serde_json::Value = serde_json::from_slice(query).unwrap();
+        let check = &query["CheckTokenCompliance"];
+        assert_eq!(check["token_address"], contract_address(1));
+        assert!(check["from"].is_null());
+        assert_eq!(check["amount"], "1000");
+        Ok(serde_json::to_vec(&(check["to"] == contract_address(9))).unwrap())
+    });
+
+    client.mint(mint_request(signer, &minter)).await.unwrap();
+
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    assert_eq!(msg.sender, minter.to_string());
+    let mint: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        mint,
+        cw20::Cw20ExecuteMsg::Mint {
+            recipient: contract_address(9),
+            amount: 1_000u128.into(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_mint_to_non_compliant_recipient_is_rejected() {
+    let (client, transport, signer, minter) = setup();
+    transport
+        .set_contract_response(&contract_address(3), &false)
+        .unwrap();
+
+    let err = client
+        .mint(mint_request(signer.clone(), &minter))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::NotCompliant(ref address) if *address == contract_address(9)));
+    assert!(transport.broadcast_txs().unwrap().is_empty());
+
+    client
+        .mint(MintRequest {
+            check_compliance: false,
+            ..mint_request(signer, &minter)
+        })
+        .await
+        .unwrap();
+    assert_eq!(transport.broadcast_txs().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_burn_from_checks_allowance() {
+    let (client, transport, signer, spender) = setup();
+    set_allowance(
+        &transport,
+        cw20::AllowanceResponse {
+            allowance: 50u128.into(),
+            expires: Expiration::Never {},
+        },
+    );
+    let request = |amount| BurnFromRequest {
+        spender: spender.clone().into(),
+        owner: contract_address(7).parse().unwrap(),
+        amount,
+        signer: signer.clone(),
+        gas_limit: GasSetting::Fixed(200_000),
+    };
+
+    let err = client.burn_from(request(80)).await.unwrap_err();
+    assert!(matches!(err, RwaError::InsufficientAllowance { .. }));
+
+    client.burn_from(request(50)).await.unwrap();
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    let burn: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        burn,
+        cw20::Cw20ExecuteMsg::BurnFrom {
+            owner: contract_address(7),
+            amount: 50u128.into(),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_minter_is_handed_over() {
+    let (client, transport, signer, minter) = setup();
+    transport
+        .set_contract_response(
+            &contract_address(1),
+            &Some(cw20::MinterResponse {
+                minter: minter.to_string(),
+                cap: Some(1_000_000u128.into()),
+            }),
+        )
+        .unwrap();
+
+    let current = client.minter().await.unwrap().unwrap();
+    assert_eq!(current.minter, minter.to_string());
+    assert_eq!(current.cap, Some(1_000_000u128.into()));
+
+    client
+        .update_minter(UpdateMinterRequest {
+            from: minter.clone().into(),
+            new_minter: Some(contract_address(8).parse().unwrap()),
+            signer,
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+    let msg: MsgExecuteContract = transport.broadcast_txs().unwrap()[0].body.messages[0]
+        .to_msg()
+        .unwrap();
+    let update: cw20::Cw20ExecuteMsg = cosmwasm_std::from_json(&msg.msg).unwrap();
+    assert_eq!(
+        update,
+        cw20::Cw20ExecuteMsg::UpdateMinter {
+            new_minter: Some(contract_address(8)),
+        }
+    );
+
+    transport
+        .set_contract_response(&contract_address(1), &None::<cw20::MinterResponse>)
+        .unwrap();
+    assert!(client.minter().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_agent_freezes_addresses_and_tokens() {
+    let (client, transport, signer, agent) = setup();
+
+    client
+        .set_address_frozen(SetAddressFrozenRequest {
+            from: agent.clone().into(),
+            address: contract_address(9).parse().unwrap(),
+            freeze: true,
+            signer: signer.clone(),
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+    client
+        .batch_freeze_partial_tokens(BatchPartialFreezeRequest {
+            from: agent.clone().into(),
+            amounts: vec![
+                (contract_address(8).parse().unwrap(), 10),
+                (contract_address(9).parse().unwrap(), 20),
+            ],
+            signer: signer.clone(),
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+    client
+        .batch(&Address::from(&agent))
+        .unfreeze_partial_tokens(&address_of(8), 5)
+        .set_address_frozen(&address_of(9), false)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let msgs: Vec<serde_json::Value> = transport
+        .broadcast_txs()
+        .unwrap()
+        .iter()
+        .flat_map(|tx| tx.body.messages.clone())
+        .map(|msg| {
+            let msg: MsgExecuteContract = msg.to_msg().unwrap();
+            serde_json::from_slice(&msg.msg).unwrap()
+        })
+        .collect();
+    assert_eq!(
+        msgs,
+        vec![
+            serde_json::json!({
+                "set_address_frozen": { "address": contract_address(9), "freeze": true }
+            }),
+            serde_json::json!({
+                "batch_freeze_partial_tokens": {
+                    "addresses": [contract_address(8), contract_address(9)],
+                    "amounts": ["10", "20"],
+                }
+            }),
+            serde_json::json!({
+                "unfreeze_partial_tokens": { "address": contract_address(8), "amount": "5" }
+            }),
+            serde_json::json!({
+                "set_address_frozen": { "address": contract_address(9), "freeze": false }
+            }),
+        ]
+    );
+}
+
+fn set_token_paused(transport: &MockTransport, paused: bool) {
+    transport.on_contract_query(&contract_address(1), move |query| {
+        let query: serde_json::Value = serde_json::from_slice(query).unwrap();
+        assert_eq!(query, serde_json::json!({ "paused": {} }));
+        Ok(serde_json::to_vec(&paused).unwrap())
+    });
+}
+
+#[tokio::test]
+async fn test_agent_pauses_and_unpauses_token() {
+    let (client, transport, signer, agent) = setup();
+    set_token_paused(&transport, true);
+    assert!(client.paused().await.unwrap());
+
+    client
+        .pause(PauseRequest {
+            from: agent.clone().into(),
+            signer: signer.clone(),
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+    client
+        .unpause(PauseRequest {
+            from: agent.clone().into(),
+            signer: signer.clone(),
+            gas_limit: GasSetting::Fixed(200_000),
+        })
+        .await
+        .unwrap();
+    client
+        .batch(&Address::from(&agent))
+        .pause()
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+
+    let msgs: Vec<serde_json::Value> = transport
+        .broadcast_txs()
+        .unwrap()
+        .iter()
+        .flat_map(|tx| tx.body.messages.clone())
+        .map(|msg| {
+            let msg: MsgExecuteContract = msg.to_msg().unwrap();
+            assert_eq!(msg.sender, agent.to_string());
+            serde_json::from_slice(&msg.msg).unwrap()
+        })
+        .collect();
+    assert_eq!(
+        msgs,
+        vec![
+            serde_json::json!({ "pause": {} }),
+            serde_json::json!({ "unpause": {} }),
+            serde_json::json!({ "pause": {} }),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_transfers_fail_fast_while_paused() {
+    let (client, transport, signer, sender) = setup();
+    set_token_paused(&transport, true);
+
+    let err = client
+        .transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::TokenPaused(ref token) if *token == contract_address(1)));
+
+    let err = client
+        .mint(MintRequest {
+            check_compliance: false,
+            ..mint_request(signer.clone(), &sender)
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::TokenPaused(_)));
+
+    let err = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 1_000)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::TokenPaused(_)));
+    assert!(transport.broadcast_txs().unwrap().is_empty());
+
+    set_token_paused(&transport, false);
+    client
+        .batch(&Address::from(&sender))
+        .mint(&address_of(9), 1_000)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap();
+    assert_eq!(transport.broadcast_txs().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_offline_transfers_fail_fast_while_paused() {
+    let (client, transport, signer, sender) = setup();
+    let (multisig, _, address) = multisig_setup(&transport);
+    set_token_paused(&transport, true);
+
+    let err = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 100)
+        .build_unsigned(signer.public_key(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::TokenPaused(_)));
+
+    let err = client
+        .batch(&Address::from(&address))
+        .mint(&address_of(9), 100)
+        .build_multisig(&multisig, GasSetting::Fixed(200_000))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::TokenPaused(_)));
+}
+
+#[tokio::test]
+async fn test_malformed_paused_response_is_reported() {
+    let (client, transport, signer, sender) = setup();
+    transport
+        .set_contract_response(&contract_address(1), &"paused")
+        .unwrap();
+
+    let err = client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::Encoding(_)));
+    assert!(transport.broadcast_txs().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failing_paused_query_is_reported() {
+    let (client, transport, signer, sender) = setup();
+    transport.on_contract_query(&contract_address(1), |_| {
+        Err("out of gas in location: wasm contract".to_string())
+    });
+
+    let err = client
+        .transfer(transfer_request(
+            signer.clone(),
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::Contract(ref log) if log.contains("out of gas")));
+
+    let err = client
+        .batch(&Address::from(&sender))
+        .transfer(&address_of(9), 1_000)
+        .execute(signer.as_ref(), GasSetting::Fixed(200_000))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RwaError::Contract(_)));
+    assert!(transport.broadcast_txs().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_token_without_paused_query_is_not_checked() {
+    let (client, transport, signer, sender) = setup();
+    transport.on_contract_query(&contract_address(1), |_| {
+        Err(
+            "Error parsing into type cw20_base::msg::QueryMsg: unknown variant `paused`"
+                .to_string(),
+        )
+    });
+
+    client
+        .transfer(transfer_request(
+            signer,
+            &sender,
+            GasSetting::Fixed(200_000),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(transport.broadcast_txs().unwrap().len(), 1);
+}
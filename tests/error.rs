@@ -0,0 +1,67 @@
+use erc3643sdk::{RwaError, TxFailure};
+
+fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+#[test]
+fn test_error_is_send_sync() {
+    assert_send_sync::<RwaError>();
+}
+
+#[test]
+fn test_out_of_gas_detection() {
+    let out_of_gas = RwaError::DeliverTx(Box::new(TxFailure {
+        tx_hash: "ABCD".to_string(),
+        height: 10,
+        code: 11,
+        codespace: "sdk".to_string(),
+        log: "out of gas in location: wasm contract; gasWanted: 100, gasUsed: 120".to_string(),
+        contract_error: None,
+        gas_wanted: 100,
+        gas_used: 120,
+    }));
+    assert!(out_of_gas.is_out_of_gas());
+
+    let contract_failure = RwaError::DeliverTx(Box::new(TxFailure {
+        tx_hash: "ABCD".to_string(),
+        height: 10,
+        code: 5,
+        codespace: "wasm".to_string(),
+        log: "failed to execute message; message index: 0: Unauthorized: execute wasm contract failed"
+            .to_string(),
+        contract_error: Some("Unauthorized".to_string()),
+        gas_wanted: 100,
+        gas_used: 80,
+    }));
+    assert!(!contract_failure.is_out_of_gas());
+    assert_eq!(
+        contract_failure
+            .tx_failure()
+            .unwrap()
+            .contract_error
+            .as_deref(),
+        Some("Unauthorized")
+    );
+}
+
+#[test]
+fn test_sequence_mismatch_detection() {
+    let mismatch = RwaError::CheckTx(Box::new(TxFailure {
+        tx_hash: "ABCD".to_string(),
+        height: 0,
+        code: 32,
+        codespace: "sdk".to_string(),
+        log: "account sequence mismatch, expected 12, got 9: incorrect account sequence"
+            .to_string(),
+        contract_error: None,
+        gas_wanted: 0,
+        gas_used: 0,
+    }));
+    assert!(mismatch.is_sequence_mismatch());
+    assert_eq!(mismatch.tx_failure().unwrap().expected_sequence(), Some(12));
+
+    let unparsable = TxFailure {
+        log: "incorrect account sequence".to_string(),
+        ..mismatch.tx_failure().unwrap().clone()
+    };
+    assert_eq!(unparsable.expected_sequence(), None);
+}
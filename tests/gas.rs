@@ -0,0 +1,53 @@
+use cosmwasm_std::Decimal;
+use erc3643sdk::{GasConfig, GasPrice, GasSetting};
+
+#[test]
+fn test_estimate_applies_multiplier() {
+    let config = GasConfig {
+        floor: 0,
+        ceiling: 1_000_000,
+    };
+    assert_eq!(config.estimate(100_000, 1.5), 150_000);
+    // Fractional gas is rounded up
+    assert_eq!(config.estimate(3, 1.5), 5);
+}
+
+#[test]
+fn test_estimate_is_clamped_to_bounds() {
+    let config = GasConfig {
+        floor: 50_000,
+        ceiling: 200_000,
+    };
+    assert_eq!(config.estimate(1_000, 1.3), 50_000);
+    assert_eq!(config.estimate(500_000, 1.3), 200_000);
+}
+
+#[test]
+fn test_gas_setting_from_fixed_limit() {
+    assert_eq!(GasSetting::from(250_000), GasSetting::Fixed(250_000));
+    assert!(matches!(GasSetting::default(), GasSetting::Auto { .. }));
+}
+
+#[test]
+fn test_parse_gas_prices() {
+    let prices = GasPrice::parse_list("0.025ukii, 0.1ibc/27394FB0").unwrap();
+    assert_eq!(prices.len(), 2);
+    assert_eq!(
+        prices[0],
+        GasPrice::new(Decimal::permille(25), "ukii").unwrap()
+    );
+    assert_eq!(prices[1].denom, "ibc/27394FB0");
+    assert_eq!(prices[0].to_string(), "0.025ukii");
+
+    assert!("ukii".parse::<GasPrice>().is_err());
+    assert!("0.025".parse::<GasPrice>().is_err());
+    assert!("abc ukii".parse::<GasPrice>().is_err());
+}
+
+#[test]
+fn test_fee_rounds_up() {
+    let price: GasPrice = "0.025ukii".parse().unwrap();
+    assert_eq!(price.fee(150_000).unwrap().amount, 3_750);
+    assert_eq!(price.fee(150_001).unwrap().amount, 3_751);
+    assert_eq!(price.fee(0).unwrap().amount, 0);
+}
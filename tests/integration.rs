@@ -2,8 +2,9 @@
 
 use cosmrs::{crypto::secp256k1, dev, rpc, tx::AccountNumber, Coin};
 use erc3643sdk::token::request::{TokenInfoRequest, TransferMessageRequest};
-use erc3643sdk::RwaClient;
+use erc3643sdk::{GasSetting, LocalSigner, RwaClient};
 use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
 
 // Constants
 const CHAIN_ID: &str = "rwa-test";
@@ -38,10 +39,12 @@ async fn test_token_transfer() {
         &sender_account_id.to_string(),
     ];
 
-    // Wrap the SigningKey in AssertUnwindSafe
-    let sender_private_key = AssertUnwindSafe(sender_private_key);
+    // Wrap the signer in AssertUnwindSafe
+    let sender_private_key = AssertUnwindSafe(Arc::new(LocalSigner::from(sender_private_key)));
 
     dev::docker_run(&docker_args, || {
+        // Capture the whole wrapper rather than its inner field
+        let sender_private_key = sender_private_key;
         let runtime = tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
@@ -61,28 +64,29 @@ async fn test_token_transfer() {
                 "cosmos1identity...",   // Replace with actual identity address
                 "cosmos1compliance...", // Replace with actual compliance address
                 DENOM,
+                1,
             )
             .unwrap();
 
             // Perform token transfer
             let transfer_request = TransferMessageRequest {
-                from: sender_account_id.to_string(),
-                to: recipient_account_id.to_string(),
+                from: sender_account_id.clone().into(),
+                to: recipient_account_id.clone().into(),
                 amount: amount.amount,
-                signer: (*sender_private_key),
-                gas_limit: 5000,
+                signer: sender_private_key.0,
+                gas_limit: GasSetting::default(),
             };
 
             let transfer_result = client.transfer(transfer_request).await.unwrap();
-            println!("Transfer hash: {}", transfer_result);
+            println!("Transfer hash: {}", transfer_result.tx_hash);
 
             // Verify transfer
-            let tx = dev::poll_for_tx(&rpc_client, transfer_result.parse().unwrap()).await;
+            let tx = dev::poll_for_tx(&rpc_client, transfer_result.tx_hash.parse().unwrap()).await;
             assert!(tx.auth_info.fee.amount.len() > 0);
 
             // Check recipient balance
             let balance_request = TokenInfoRequest {
-                address: recipient_account_id.to_string(),
+                address: recipient_account_id.clone().into(),
             };
             let balance = client.balance(balance_request).await.unwrap();
             assert_eq!(balance.balance.u128(), amount.amount);
@@ -0,0 +1,136 @@
+use std::path::PathBuf;
+
+use erc3643sdk::{HdPath, KeyType, Keystore, LocalSigner, RwaError, Signer, Wallet};
+
+const KEY: [u8; 32] = [3; 32];
+const PASSWORD: &str = "correct horse battery staple";
+const MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Opens an empty keystore with a cheap scrypt cost in a fresh directory.
+fn keystore(test: &str) -> Keystore {
+    let dir: PathBuf = std::env::temp_dir().join(format!(
+        "erc3643sdk-keystore-{}-{}",
+        test,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    Keystore::open(dir).unwrap().with_scrypt_log_n(4)
+}
+
+#[tokio::test]
+async fn test_keystore_round_trip() {
+    let keystore = keystore("round-trip");
+    let local = LocalSigner::from_slice(&KEY).unwrap();
+
+    let info = keystore.import("issuer", &KEY, PASSWORD).unwrap();
+    assert_eq!(info.public_key, local.public_key());
+    assert_eq!(
+        info.account_id("kii").unwrap(),
+        local.account_id("kii").unwrap()
+    );
+
+    let signer = keystore.signer("issuer", PASSWORD).unwrap();
+    assert_eq!(
+        signer.sign(b"sign bytes").await.unwrap(),
+        local.sign(b"sign bytes").await.unwrap()
+    );
+    assert_eq!(keystore.export("issuer", PASSWORD).unwrap().as_slice(), KEY);
+
+    let contents = std::fs::read_to_string(keystore.dir().join("issuer.json")).unwrap();
+    assert!(!contents.contains(&hex::encode(KEY)));
+}
+
+#[test]
+fn test_keystore_rejects_wrong_password() {
+    let keystore = keystore("wrong-password");
+    keystore.import("issuer", &KEY, PASSWORD).unwrap();
+
+    let err = keystore.signer("issuer", "hunter2").unwrap_err();
+
+    assert!(matches!(err, RwaError::Keystore(ref reason) if reason.contains("wrong password")));
+}
+
+#[test]
+fn test_keystore_list_and_delete() {
+    let keystore = keystore("list");
+    let wallet = Wallet::from_mnemonic(MNEMONIC, "").unwrap();
+    keystore
+        .import_mnemonic("agent", &wallet, &HdPath::cosmos(1), PASSWORD)
+        .unwrap();
+    keystore.import("issuer", &KEY, PASSWORD).unwrap();
+    std::fs::write(keystore.dir().join("settings.json"), "{}").unwrap();
+    std::fs::write(keystore.dir().join("notes.txt"), "not a key").unwrap();
+
+    let names: Vec<String> = keystore
+        .list()
+        .unwrap()
+        .into_iter()
+        .map(|key| key.name)
+        .collect();
+    assert_eq!(names, ["agent", "issuer"]);
+    assert_eq!(
+        keystore.get("agent").unwrap().public_key,
+        wallet.account(1).unwrap().public_key()
+    );
+
+    keystore.delete("agent").unwrap();
+    assert_eq!(keystore.list().unwrap().len(), 1);
+    assert!(matches!(
+        keystore.get("agent"),
+        Err(RwaError::Keystore(ref reason)) if reason.contains("no key named")
+    ));
+}
+
+#[test]
+fn test_keystore_keeps_ethereum_key_type() {
+    let keystore = keystore("ethereum");
+    let wallet = Wallet::from_mnemonic(MNEMONIC, "").unwrap();
+
+    let info = keystore
+        .import_mnemonic("metamask", &wallet, &HdPath::ethereum(0), PASSWORD)
+        .unwrap();
+
+    assert_eq!(info.key_type, KeyType::EthSecp256k1);
+    let expected = wallet.derive(&HdPath::ethereum(0)).unwrap();
+    assert_eq!(
+        info.account_id("kii").unwrap(),
+        expected.account_id("kii").unwrap()
+    );
+    let signer = keystore.signer("metamask", PASSWORD).unwrap();
+    assert_eq!(signer.key_type(), KeyType::EthSecp256k1);
+    assert_eq!(keystore.get("metamask").unwrap(), info);
+}
+
+#[test]
+fn test_keystore_list_reports_damaged_key_files() {
+    let keystore = keystore("damaged");
+    keystore.import("issuer", &KEY, PASSWORD).unwrap();
+    let path = keystore.dir().join("issuer.json");
+    let contents = std::fs::read_to_string(&path).unwrap();
+
+    std::fs::write(&path, &contents[..contents.len() / 2]).unwrap();
+    assert!(matches!(
+        keystore.list(),
+        Err(RwaError::Keystore(ref reason)) if reason.contains("invalid key file")
+    ));
+
+    std::fs::write(&path, contents.replace("\"version\": 1", "\"version\": 2")).unwrap();
+    assert!(matches!(
+        keystore.list(),
+        Err(RwaError::Keystore(ref reason)) if reason.contains("unsupported version")
+    ));
+}
+
+#[test]
+fn test_keystore_refuses_overwrite_and_invalid_names() {
+    let keystore = keystore("overwrite");
+    keystore.import("issuer", &KEY, PASSWORD).unwrap();
+
+    let err = keystore.import("issuer", &[4; 32], PASSWORD).unwrap_err();
+    assert!(matches!(err, RwaError::Keystore(ref reason) if reason.contains("already exists")));
+    assert_eq!(keystore.export("issuer", PASSWORD).unwrap().as_slice(), KEY);
+
+    assert!(keystore.import("../issuer", &KEY, PASSWORD).is_err());
+    assert!(keystore.import("", &KEY, PASSWORD).is_err());
+}
@@ -0,0 +1,230 @@
+use cosmwasm_std::Binary;
+use erc3643sdk::signer::ETHEREUM_COIN_TYPE;
+use erc3643sdk::{
+    Address, HdPath, KeyType, LocalSigner, MultisigAccount, RemoteSigner, RwaError, Signer, Wallet,
+};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+const KEY: [u8; 32] = [3; 32];
+const KEY_ID: &str = "issuer";
+const MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Serves the remote signer protocol for a single key on a random local port.
+async fn spawn_signing_service() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let signer = LocalSigner::from_slice(&KEY).unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut line = String::new();
+            stream.read_line(&mut line).await.unwrap();
+            let request: Value = serde_json::from_str(&line).unwrap();
+
+            let response = if request["key_id"] != KEY_ID {
+                json!({ "error": "unknown key" })
+            } else if request["method"] == "public_key" {
+                json!({ "public_key": Binary::from(signer.public_key().to_bytes()) })
+            } else {
+                let sign_bytes: Binary =
+                    serde_json::from_value(request["sign_bytes"].clone()).unwrap();
+                let signature = signer.sign(&sign_bytes).await.unwrap();
+                json!({ "signature": Binary::from(signature) })
+            };
+
+            let mut response = serde_json::to_vec(&response).unwrap();
+            response.push(b'\n');
+            stream.get_mut().write_all(&response).await.unwrap();
+        }
+    });
+
+    address
+}
+
+#[tokio::test]
+async fn test_local_signer_address() {
+    let signer = LocalSigner::from_slice(&KEY).unwrap();
+    let account_id = signer.account_id("kii").unwrap();
+    assert_eq!(account_id.prefix(), "kii");
+    assert_eq!(account_id, signer.public_key().account_id("kii").unwrap());
+    assert_eq!(signer.sign(b"sign bytes").await.unwrap().len(), 64);
+}
+
+#[tokio::test]
+async fn test_remote_signer_matches_local_key() {
+    let address = spawn_signing_service().await;
+    let local = LocalSigner::from_slice(&KEY).unwrap();
+
+    let remote = RemoteSigner::connect(&address, KEY_ID).await.unwrap();
+
+    assert_eq!(remote.public_key(), local.public_key());
+    assert_eq!(
+        remote.sign(b"sign bytes").await.unwrap(),
+        local.sign(b"sign bytes").await.unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_remote_signer_reports_refusal() {
+    let address = spawn_signing_service().await;
+
+    let err = RemoteSigner::connect(&address, "unknown")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RwaError::Signing(ref reason) if reason.contains("unknown key")));
+}
+
+#[test]
+fn test_wallet_derives_cosmos_address() {
+    let wallet = Wallet::from_mnemonic(MNEMONIC, "")
+        .unwrap()
+        .with_prefix("cosmos");
+
+    assert_eq!(
+        wallet.address(0).unwrap().to_string(),
+        "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"
+    );
+    assert_eq!(
+        wallet.account(0).unwrap().public_key(),
+        wallet.derive(&HdPath::cosmos(0)).unwrap().public_key()
+    );
+}
+
+#[test]
+fn test_wallet_derives_indexed_accounts() {
+    let wallet = Wallet::from_mnemonic(MNEMONIC, "").unwrap();
+
+    let accounts = wallet.accounts(3).unwrap();
+
+    assert_eq!(accounts.len(), 3);
+    assert_ne!(accounts[0].public_key(), accounts[1].public_key());
+    assert_ne!(accounts[1].public_key(), accounts[2].public_key());
+    assert_eq!(
+        accounts[2].public_key(),
+        wallet.derive(&HdPath::cosmos(2)).unwrap().public_key()
+    );
+    assert_eq!(wallet.address(1).unwrap().prefix(), "kii");
+    assert_ne!(
+        accounts[0].public_key(),
+        wallet.derive(&HdPath::ethereum(0)).unwrap().public_key()
+    );
+}
+
+#[test]
+fn test_wallet_defaults_to_kii_cosmos_accounts() {
+    // The Keplr address of the mnemonic, `cosmos19rl4...` on the Cosmos Hub
+    let expected = Address::from_bech32("cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4")
+        .unwrap()
+        .to_bech32("kii")
+        .unwrap();
+    let wallet = Wallet::from_mnemonic(MNEMONIC, "").unwrap();
+
+    assert_eq!(wallet.address(0).unwrap().to_string(), expected);
+}
+
+#[test]
+fn test_wallet_derives_ethereum_accounts() {
+    // The MetaMask account of the mnemonic
+    let expected = Address::from_hex("0x9858EfFD232B4033E47d90003D41EC34EcaEda94")
+        .unwrap()
+        .to_bech32("kii")
+        .unwrap();
+    let wallet = Wallet::from_mnemonic(MNEMONIC, "")
+        .unwrap()
+        .with_coin_type(ETHEREUM_COIN_TYPE);
+
+    assert_eq!(wallet.address(0).unwrap().to_string(), expected);
+    assert_eq!(wallet.account(0).unwrap().key_type(), KeyType::EthSecp256k1);
+
+    let derived = wallet.derive(&HdPath::ethereum(0)).unwrap();
+    assert_eq!(derived.account_id("kii").unwrap().to_string(), expected);
+    assert_eq!(
+        wallet.derive(&HdPath::cosmos(0)).unwrap().key_type(),
+        KeyType::Secp256k1
+    );
+}
+
+#[tokio::test]
+async fn test_eth_signer_signs_keccak_digest() {
+    let signer = LocalSigner::from_slice_with_key_type(&KEY, KeyType::EthSecp256k1).unwrap();
+    let cosmos = LocalSigner::from_slice(&KEY).unwrap();
+    assert_eq!(signer.public_key(), cosmos.public_key());
+    assert_ne!(
+        signer.account_id("kii").unwrap(),
+        cosmos.account_id("kii").unwrap()
+    );
+
+    let signature = signer.sign(b"sign bytes").await.unwrap();
+
+    assert_eq!(signature.len(), 65);
+    let digest = Keccak256::digest(b"sign bytes");
+    let recovered = VerifyingKey::recover_from_prehash(
+        &digest,
+        &Signature::from_slice(&signature[..64]).unwrap(),
+        RecoveryId::from_byte(signature[64]).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        recovered.to_sec1_bytes().to_vec(),
+        signer.public_key().to_bytes()
+    );
+}
+
+#[test]
+fn test_wallet_passphrase_changes_keys() {
+    let plain = Wallet::from_mnemonic(MNEMONIC, "").unwrap();
+    let protected = Wallet::from_mnemonic(MNEMONIC, "secret").unwrap();
+
+    assert_ne!(plain.address(0).unwrap(), protected.address(0).unwrap());
+}
+
+#[test]
+fn test_wallet_rejects_invalid_mnemonic() {
+    let err = Wallet::from_mnemonic("abandon abandon abandon", "").unwrap_err();
+    assert!(matches!(err, RwaError::Signing(_)));
+}
+
+#[test]
+fn test_hd_path_parsing() {
+    let path: HdPath = "m/44'/60'/0'/0/7".parse().unwrap();
+    assert_eq!(path, HdPath::ethereum(7));
+    assert_eq!(path.to_string(), "m/44'/60'/0'/0/7");
+
+    assert!("m/44'/60'/0'/0".parse::<HdPath>().is_err());
+    assert!("m/49'/60'/0'/0/0".parse::<HdPath>().is_err());
+    assert!("m/44'/60/0'/0/0".parse::<HdPath>().is_err());
+}
+
+#[test]
+fn test_multisig_account_address() {
+    let keys: Vec<_> = (1..=3)
+        .map(|key| LocalSigner::from_slice(&[key; 32]).unwrap().public_key())
+        .collect();
+
+    let multisig = MultisigAccount::new(2, keys.clone()).unwrap();
+    let address = multisig.account_id("kii").unwrap();
+    assert_eq!(address.prefix(), "kii");
+    assert_eq!(address.to_bytes().len(), 20);
+
+    let reordered = MultisigAccount::new(2, keys.iter().rev().cloned().collect()).unwrap();
+    assert_ne!(reordered.account_id("kii").unwrap(), address);
+    let three_of_three = MultisigAccount::new(3, keys.clone()).unwrap();
+    assert_ne!(three_of_three.account_id("kii").unwrap(), address);
+
+    assert!(matches!(
+        MultisigAccount::new(0, keys.clone()),
+        Err(RwaError::Config(_))
+    ));
+    assert!(matches!(
+        MultisigAccount::new(4, keys),
+        Err(RwaError::Config(_))
+    ));
+}